@@ -1,24 +1,115 @@
 use std::mem;
+use std::slice;
+use std::ops::Deref;
 use std::marker::PhantomData;
 
 use ffi::*;
-use ::{Error, Sample};
+use sys::*;
+use listener::Current;
+use ::{Error, Sample, extension};
+
+/// The channel ordering of ambisonic sample data.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum AmbisonicLayout {
+	/// Furse-Malham ordering.
+	FuMa,
+
+	/// Ambisonic Channel Number ordering.
+	Acn,
+}
+
+/// The channel normalization of ambisonic sample data.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum AmbisonicScaling {
+	/// Furse-Malham normalization.
+	FuMa,
+
+	/// Schmidt semi-normalization.
+	Sn3d,
+
+	/// Full 3D normalization.
+	N3d,
+}
+
+/// Describes ambisonic B-Format sample data.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Ambisonic {
+	/// Whether the soundfield has height (3D) or is horizontal only (2D).
+	pub periphonic: bool,
+
+	/// The ambisonic order, orders above the first require
+	/// `AL_SOFT_bformat_hoa`.
+	pub order: u8,
+
+	/// The channel ordering, Furse-Malham if not specified.
+	pub layout: Option<AmbisonicLayout>,
+
+	/// The channel normalization, Furse-Malham if not specified.
+	pub scaling: Option<AmbisonicScaling>,
+}
+
+impl Ambisonic {
+	/// The number of channels for the order and dimensions.
+	pub fn channels(&self) -> u16 {
+		let order = self.order as u16;
+
+		if self.periphonic {
+			(order + 1) * (order + 1)
+		}
+		else {
+			order * 2 + 1
+		}
+	}
+
+	fn format<T: Sample>(&self) -> Result<ALenum, Error> {
+		let format = match (self.periphonic, try!(<T as Sample>::format(1))) {
+			(false, AL_FORMAT_MONO8)        => AL_FORMAT_BFORMAT2D_8,
+			(false, AL_FORMAT_MONO16)       => AL_FORMAT_BFORMAT2D_16,
+			(false, AL_FORMAT_MONO_FLOAT32) => AL_FORMAT_BFORMAT2D_FLOAT32,
+			(true,  AL_FORMAT_MONO8)        => AL_FORMAT_BFORMAT3D_8,
+			(true,  AL_FORMAT_MONO16)       => AL_FORMAT_BFORMAT3D_16,
+			(true,  AL_FORMAT_MONO_FLOAT32) => AL_FORMAT_BFORMAT3D_FLOAT32,
+			_                               => return Err(Error::InvalidValue),
+		};
+
+		if !extension::is_supported("AL_EXT_BFORMAT") {
+			return Err(Error::Unsupported("AL_EXT_BFORMAT"));
+		}
+
+		Ok(format)
+	}
+}
+
+impl Default for Ambisonic {
+	fn default() -> Self {
+		Ambisonic {
+			periphonic: true,
+			order:      1,
+			layout:     None,
+			scaling:    None,
+		}
+	}
+}
 
 /// A buffer holding sample data.
 #[derive(PartialEq, Eq)]
 pub struct Buffer<'a> {
-	id: ALuint,
+	id:      ALuint,
+	context: *mut ALCcontext,
 
 	_marker: PhantomData<&'a ()>,
 }
 
+unsafe impl<'a> Send for Buffer<'a> { }
+unsafe impl<'a> Sync for Buffer<'a> { }
+
 impl<'a> Buffer<'a> {
 	#[doc(hidden)]
 	pub unsafe fn empty() -> Result<Self, Error> {
 		let mut id = 0;
 		al_try!(alGenBuffers(1, &mut id));
 
-		Ok(Buffer { id: id, _marker: PhantomData })
+		Ok(Buffer { id: id, context: Current::get(), _marker: PhantomData })
 	}
 
 	#[doc(hidden)]
@@ -42,15 +133,74 @@ impl<'a> Buffer<'a> {
 		Ok(())
 	}
 
+	#[doc(hidden)]
+	pub unsafe fn ambisonic<T: Sample>(ambisonic: &Ambisonic, data: &[T], rate: u32) -> Result<Self, Error> {
+		let mut buffer = try!(Buffer::empty());
+
+		match buffer.fill_ambisonic(ambisonic, data, rate) {
+			Ok(..) =>
+				Ok(buffer),
+
+			Err(error) =>
+				Err(error)
+		}
+	}
+
+	#[doc(hidden)]
+	pub unsafe fn fill_ambisonic<T: Sample>(&mut self, ambisonic: &Ambisonic, data: &[T], rate: u32) -> Result<(), Error> {
+		let format = try!(ambisonic.format::<T>());
+
+		if ambisonic.layout.is_some() || ambisonic.scaling.is_some() {
+			if !extension::is_supported("AL_SOFT_bformat_ex") {
+				return Err(Error::Unsupported("AL_SOFT_bformat_ex"));
+			}
+
+			let layout = match ambisonic.layout.unwrap_or(AmbisonicLayout::FuMa) {
+				AmbisonicLayout::FuMa => AL_FUMA_SOFT,
+				AmbisonicLayout::Acn  => AL_ACN_SOFT,
+			};
+
+			let scaling = match ambisonic.scaling.unwrap_or(AmbisonicScaling::FuMa) {
+				AmbisonicScaling::FuMa => AL_FUMA_SOFT,
+				AmbisonicScaling::Sn3d => AL_SN3D_SOFT,
+				AmbisonicScaling::N3d  => AL_N3D_SOFT,
+			};
+
+			al_try!(alBufferi(self.id, AL_AMBISONIC_LAYOUT_SOFT, layout));
+			al_try!(alBufferi(self.id, AL_AMBISONIC_SCALING_SOFT, scaling));
+		}
+
+		if ambisonic.order > 1 {
+			if !extension::is_supported("AL_SOFT_bformat_hoa") {
+				return Err(Error::Unsupported("AL_SOFT_bformat_hoa"));
+			}
+
+			al_try!(alBufferi(self.id, AL_UNPACK_AMBISONIC_ORDER_SOFT, ambisonic.order as ALint));
+		}
+
+		al_try!(alBufferData(self.id, format, data.as_ptr() as *const _,
+			(mem::size_of::<T>() * data.len()) as ALsizei, rate as ALint));
+
+		Ok(())
+	}
+
 	#[doc(hidden)]
 	pub unsafe fn id(&self) -> ALuint {
 		self.id
 	}
+
+	// Make the owning context current for the duration of a call, buffer names
+	// are only valid on the device they were created on.
+	fn scoped(&self) -> Result<Option<Current>, Error> {
+		Current::scope(self.context)
+	}
 }
 
 impl<'a> Buffer<'a> {
 	/// The sample rate of the data in the buffer.
 	pub fn rate(&self) -> u32 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0;
 			alGetBufferi(self.id, AL_FREQUENCY, &mut value);
@@ -61,6 +211,8 @@ impl<'a> Buffer<'a> {
 
 	/// The bit depth of the data in the buffer.
 	pub fn bits(&self) -> u16 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0;
 			alGetBufferi(self.id, AL_BITS, &mut value);
@@ -71,6 +223,8 @@ impl<'a> Buffer<'a> {
 
 	/// The number of channels of the data in the buffer.
 	pub fn channels(&self) -> u16 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0;
 			alGetBufferi(self.id, AL_CHANNELS, &mut value);
@@ -81,6 +235,8 @@ impl<'a> Buffer<'a> {
 
 	/// The number of samples in the buffer.
 	pub fn len(&self) -> usize {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0;
 			alGetBufferi(self.id, AL_SIZE, &mut value);
@@ -88,6 +244,42 @@ impl<'a> Buffer<'a> {
 			value as usize
 		}
 	}
+
+	/// The start and end of the loop region, in sample frames.
+	pub fn loop_points(&self) -> (usize, usize) {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = [0; 2];
+			alGetBufferiv(self.id, AL_LOOP_POINTS_SOFT, value.as_mut_ptr());
+
+			(value[0] as usize, value[1] as usize)
+		}
+	}
+
+	/// Sets the start and end of the loop region, in sample frames.
+	///
+	/// A looping `Source` will play the whole buffer once and then repeat
+	/// only the loop region, the buffer must not be attached to any `Source`.
+	pub fn set_loop_points(&mut self, start: usize, end: usize) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		if !extension::is_supported("AL_SOFT_loop_points") {
+			return Err(Error::Unsupported("AL_SOFT_loop_points"));
+		}
+
+		let frames = self.len() / (self.channels() as usize * self.bits() as usize / 8).max(1);
+
+		if start >= end || end > frames {
+			return Err(Error::InvalidValue);
+		}
+
+		unsafe {
+			al_try!(alBufferiv(self.id, AL_LOOP_POINTS_SOFT, [start as ALint, end as ALint].as_ptr()));
+		}
+
+		Ok(())
+	}
 }
 
 impl<'a> ::std::fmt::Debug for Buffer<'a> {
@@ -104,9 +296,72 @@ impl<'a> ::std::fmt::Debug for Buffer<'a> {
 
 impl<'a> Drop for Buffer<'a> {
 	fn drop(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alDeleteBuffers(1, &self.id);
 			al_panic!();
 		}
 	}
 }
+
+type Callback<T> = Box<dyn FnMut(&mut [T]) -> usize + Send>;
+
+/// A buffer pulling its sample data from a callback.
+///
+/// The callback is called from the mixer with the slice to fill and returns
+/// the number of samples it wrote, writing fewer samples than requested ends
+/// the playback. It's attached to a `Source` like any other `Buffer`.
+pub struct CallbackBuffer<'a, T: Sample> {
+	buffer:    Buffer<'a>,
+	_callback: Box<Callback<T>>,
+}
+
+impl<'a, T: Sample> CallbackBuffer<'a, T> {
+	#[doc(hidden)]
+	pub unsafe fn new<F>(channels: u16, rate: u32, callback: F) -> Result<Self, Error>
+		where F: FnMut(&mut [T]) -> usize + Send + 'static
+	{
+		if !extension::is_supported("AL_SOFT_callback_buffer") {
+			return Err(Error::Unsupported("AL_SOFT_callback_buffer"));
+		}
+
+		let     format   = try!(<T as Sample>::format(channels));
+		let     buffer   = try!(Buffer::empty());
+		let mut callback = Box::new(Box::new(callback) as Callback<T>);
+
+		al_try!(alBufferCallbackSOFT(buffer.id(), format, rate as ALsizei, Some(trampoline::<T>),
+			&mut *callback as *mut Callback<T> as *mut _));
+
+		Ok(CallbackBuffer {
+			buffer:    buffer,
+			_callback: callback,
+		})
+	}
+}
+
+unsafe extern "C" fn trampoline<T: Sample>(user: *mut ALvoid, data: *mut ALvoid, size: ALsizei) -> ALsizei {
+	let callback = &mut *(user as *mut Callback<T>);
+	let data     = slice::from_raw_parts_mut(data as *mut T, size as usize / mem::size_of::<T>());
+	let written  = callback(data).min(data.len());
+
+	(written * mem::size_of::<T>()) as ALsizei
+}
+
+impl<'a, T: Sample> Deref for CallbackBuffer<'a, T> {
+	type Target = Buffer<'a>;
+
+	fn deref(&self) -> &<Self as Deref>::Target {
+		&self.buffer
+	}
+}
+
+impl<'a, T: Sample> ::std::fmt::Debug for CallbackBuffer<'a, T> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::CallbackBuffer("));
+		try!(f.write_str(&format!("{}; ", unsafe { self.id() })));
+		try!(f.write_str(&format!("rate={} ", self.rate())));
+		try!(f.write_str(&format!("channels={}", self.channels())));
+		f.write_str(")")
+	}
+}
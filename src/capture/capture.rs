@@ -1,14 +1,23 @@
 use std::marker::PhantomData;
 use std::ptr;
 use std::ffi::CString;
+use std::io::{Write, Seek};
+use std::time::Duration;
+use std::thread;
+use std::sync::mpsc::{channel, Receiver};
 
 use ffi::*;
-use ::{Error, Sample};
+use sys::*;
+use ::{Error, Sample, extension};
+use super::{Frames, Recorder};
 use traits::Device;
+use wav::{self, Encode};
 
 /// Represents a capture device.
 pub struct Capture<T: Sample> {
-	ptr: *mut ALCdevice,
+	ptr:      *mut ALCdevice,
+	channels: u16,
+	rate:     u32,
 
 	_marker: PhantomData<T>,
 }
@@ -17,8 +26,8 @@ unsafe impl<T: Sample> Send for Capture<T> { }
 
 impl<T: Sample> Capture<T> {
 	#[doc(hidden)]
-	pub unsafe fn wrap(ptr: *mut ALCdevice) -> Self {
-		Capture { ptr: ptr, _marker: PhantomData }
+	pub unsafe fn wrap(ptr: *mut ALCdevice, channels: u16, rate: u32) -> Self {
+		Capture { ptr: ptr, channels: channels, rate: rate, _marker: PhantomData }
 	}
 }
 
@@ -35,7 +44,7 @@ impl<T: Sample> Capture<T> {
 				Err(Error::InvalidName)
 			}
 			else {
-				Ok(Capture::wrap(ptr))
+				Ok(Capture::wrap(ptr, channels, rate))
 			}
 		}
 	}
@@ -52,11 +61,21 @@ impl<T: Sample> Capture<T> {
 				Err(Error::InvalidName)
 			}
 			else {
-				Ok(Capture::wrap(ptr))
+				Ok(Capture::wrap(ptr, channels, rate))
 			}
 		}
 	}
 
+	/// The number of channels the device was opened with.
+	pub fn channels(&self) -> u16 {
+		self.channels
+	}
+
+	/// The sample rate the device was opened with.
+	pub fn rate(&self) -> u32 {
+		self.rate
+	}
+
 	/// Starts recording.
 	pub fn start(&mut self) {
 		unsafe {
@@ -71,7 +90,7 @@ impl<T: Sample> Capture<T> {
 		}
 	}
 
-	/// Gets the number of samples available.
+	/// Gets the number of sample frames available.
 	pub fn len(&self) -> usize {
 		unsafe {
 			let mut value = 0;
@@ -81,16 +100,144 @@ impl<T: Sample> Capture<T> {
 		}
 	}
 
-	/// Takes available samples out of the device.
-	pub fn take(&mut self) -> Result<Vec<T>, Error> {
+	/// Takes available frames out of the device.
+	pub fn take(&mut self) -> Result<Frames<T>, Error> {
+		let frames = self.len();
+		self.take_frames(frames)
+	}
+
+	/// Takes at most the given number of frames out of the device.
+	pub fn take_frames(&mut self, frames: usize) -> Result<Frames<T>, Error> {
+		let frames     = self.len().min(frames);
+		let mut result = Vec::with_capacity(frames * self.channels as usize);
+
+		unsafe {
+			try!(self.capture(result.as_mut_ptr(), frames));
+			result.set_len(frames * self.channels as usize);
+		}
+
+		Ok(Frames::new(self.channels, result))
+	}
+
+	/// Reads as many whole frames as are available and fit into the slice,
+	/// returning the number of samples read.
+	pub fn read(&mut self, out: &mut [T]) -> Result<usize, Error> {
+		let frames = self.len().min(out.len() / self.channels as usize);
+
+		if frames > 0 {
+			unsafe {
+				try!(self.capture(out.as_mut_ptr(), frames));
+			}
+		}
+
+		Ok(frames * self.channels as usize)
+	}
+
+	// Devices without ALC_EXT_disconnect are assumed to stay connected.
+	fn is_connected(&self) -> bool {
+		if !extension::device::is_supported_by(self, "ALC_EXT_disconnect") {
+			return true;
+		}
+
 		unsafe {
-			let mut result = Vec::with_capacity(self.len());
+			let mut value = ALC_TRUE as ALCint;
+			alcGetIntegerv(self.as_ptr(), ALC_CONNECTED, 1, &mut value);
+
+			value == ALC_TRUE as ALCint
+		}
+	}
+
+	unsafe fn capture(&mut self, out: *mut T, frames: usize) -> Result<(), Error> {
+		al_try!(self,
+			alcCaptureSamples(self.as_mut_ptr(), out as *mut _, frames as ALCsizei));
+
+		Ok(())
+	}
 
-			al_try!(self,
-				alcCaptureSamples(self.as_mut_ptr(), result.as_mut_ptr() as *mut _, self.len() as ALCsizei));
+	/// Moves the device to a background thread recording chunks of the given
+	/// number of frames, checking for new samples at the given interval.
+	pub fn recorder<F>(self, frames: usize, interval: Duration, callback: F) -> Recorder<T>
+		where F: FnMut(Frames<T>) + Send + 'static, T: Send + 'static
+	{
+		Recorder::new(self, frames, interval, callback)
+	}
+
+	/// Moves the device to a background thread recording chunks of the given
+	/// number of frames, delivered through the returned channel.
+	pub fn chunks(self, frames: usize, interval: Duration) -> (Recorder<T>, Receiver<Frames<T>>)
+		where T: Send + 'static
+	{
+		let (sender, receiver) = channel();
+
+		(Recorder::new(self, frames, interval, move |chunk| { sender.send(chunk).ok(); }), receiver)
+	}
+
+	/// Records for the given duration into the WAV writer, starting and
+	/// stopping the device.
+	///
+	/// The writer must have been created with the same channel count and
+	/// sample rate as the device. Recording fails with `InvalidDevice` if the
+	/// device gets disconnected or delivers no samples for a second.
+	pub fn record_to<W>(&mut self, writer: &mut wav::Writer<W, T>, duration: Duration) -> Result<(), wav::Error>
+		where W: Write + Seek, T: Encode
+	{
+		let format    = writer.format();
+		let channels  = self.channels as usize;
+		let mut total = (duration.as_secs() * self.rate as u64
+			+ duration.subsec_nanos() as u64 * self.rate as u64 / 1_000_000_000) as usize;
+
+		let mut buffer = Vec::new();
+		let mut idle   = 0;
+
+		if format.channels != self.channels || format.rate != self.rate {
+			return Err(Error::InvalidValue.into());
+		}
+
+		self.start();
+
+		while total > 0 {
+			let available = self.len().min(total);
+
+			if available == 0 {
+				if idle >= 100 || !self.is_connected() {
+					self.stop();
+
+					return Err(Error::InvalidDevice.into());
+				}
+
+				idle += 1;
+				thread::sleep(Duration::from_millis(10));
 
-			Ok(result)
+				continue;
+			}
+
+			idle = 0;
+
+			buffer.clear();
+			buffer.reserve(available * channels);
+
+			unsafe {
+				if let Err(error) = self.capture(buffer.as_mut_ptr(), available) {
+					self.stop();
+
+					return Err(error.into());
+				}
+
+				buffer.set_len(available * channels);
+			}
+
+			if let Err(error) = writer.write(&buffer) {
+				self.stop();
+
+				return Err(error);
+			}
+
+			total -= available;
 		}
+
+		self.stop();
+
+		Ok(())
 	}
 }
 
@@ -103,6 +250,8 @@ unsafe impl<T: Sample> Device for Capture<T> {
 impl<T: Sample> ::std::fmt::Debug for Capture<T> {
 	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
 		try!(f.write_str("openal::Capture("));
+		try!(f.write_str(&format!("channels={} ", self.channels)));
+		try!(f.write_str(&format!("rate={} ", self.rate)));
 		try!(f.write_str(&format!("len={}; ", self.len())));
 		f.write_str(")")
 	}
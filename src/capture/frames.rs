@@ -0,0 +1,122 @@
+use std::ops::Deref;
+use std::slice;
+use std::iter::{Skip, StepBy};
+
+use Sample;
+
+/// Interleaved sample frames taken out of a `Capture`.
+///
+/// Derefs to the interleaved samples.
+pub struct Frames<T: Sample> {
+	channels: u16,
+	samples:  Vec<T>,
+}
+
+impl<T: Sample> Frames<T> {
+	#[doc(hidden)]
+	pub fn new(channels: u16, samples: Vec<T>) -> Self {
+		Frames {
+			channels: channels,
+			samples:  samples,
+		}
+	}
+}
+
+impl<T: Sample> Frames<T> {
+	/// The number of channels.
+	pub fn channels(&self) -> u16 {
+		self.channels
+	}
+
+	/// The number of frames.
+	pub fn len(&self) -> usize {
+		self.samples.len() / self.channels as usize
+	}
+
+	/// Checks if there are no frames.
+	pub fn is_empty(&self) -> bool {
+		self.samples.is_empty()
+	}
+
+	/// Gets the samples of the frame at the given index.
+	pub fn frame(&self, index: usize) -> Option<&[T]> {
+		self.samples.chunks(self.channels as usize).nth(index)
+	}
+
+	/// Iterates over the frames.
+	pub fn frames<'a>(&'a self) -> slice::Chunks<'a, T> {
+		self.samples.chunks(self.channels as usize)
+	}
+
+	/// Iterates over the samples of the given channel.
+	///
+	/// # Panics
+	///
+	/// Panics if the channel is out of range.
+	pub fn channel<'a>(&'a self, index: u16) -> Channel<'a, T> {
+		assert!(index < self.channels);
+
+		Channel(self.samples.iter().skip(index as usize).step_by(self.channels as usize))
+	}
+
+	/// Returns the interleaved samples.
+	pub fn into_inner(self) -> Vec<T> {
+		self.samples
+	}
+}
+
+impl<T: Sample> Deref for Frames<T> {
+	type Target = [T];
+
+	fn deref(&self) -> &<Self as Deref>::Target {
+		&self.samples
+	}
+}
+
+impl<T: Sample> ::std::fmt::Debug for Frames<T> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::capture::Frames("));
+		try!(f.write_str(&format!("channels={} ", self.channels)));
+		try!(f.write_str(&format!("len={}", self.len())));
+		f.write_str(")")
+	}
+}
+
+/// An iterator over the samples of a single channel.
+pub struct Channel<'a, T: Sample + 'a>(StepBy<Skip<slice::Iter<'a, T>>>);
+
+impl<'a, T: Sample> Iterator for Channel<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<&'a T> {
+		self.0.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Frames;
+
+	#[test]
+	fn channel() {
+		let frames = Frames::new(2, vec![1i16, 2, 3, 4, 5, 6]);
+
+		assert_eq!(frames.len(), 3);
+		assert_eq!(frames.channel(0).cloned().collect::<Vec<_>>(), vec![1, 3, 5]);
+		assert_eq!(frames.channel(1).cloned().collect::<Vec<_>>(), vec![2, 4, 6]);
+		assert_eq!(frames.frame(1), Some(&[3, 4][..]));
+	}
+
+	#[test]
+	fn empty() {
+		let frames = Frames::<i16>::new(2, vec![]);
+
+		assert!(frames.is_empty());
+		assert_eq!(frames.channel(1).count(), 0);
+		assert_eq!(frames.frame(0), None);
+	}
+}
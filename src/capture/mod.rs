@@ -5,10 +5,17 @@ Helpers related to `Capture`.
 mod capture;
 pub use self::capture::Capture;
 
+mod frames;
+pub use self::frames::{Frames, Channel};
+
+mod recorder;
+pub use self::recorder::Recorder;
+
 use std::ptr;
 
 use ffi::*;
 use {Error, Sample, extension};
+use util::{enumeration, string, strings};
 
 /// Opens the default capture device.
 pub fn default<T: Sample>(channels: u16, rate: u32, size: usize) -> Result<Capture<T>, Error> {
@@ -21,24 +28,24 @@ pub fn open<T: Sample>(name: &str, channels: u16, rate: u32, size: usize) -> Res
 }
 
 /// Gets a list of available capture device names.
-pub fn devices() -> Vec<&'static str> {
-	use std::ffi::CStr;
-	use std::str::from_utf8_unchecked;
-	use libc::strlen;
-
-	let mut result = Vec::new();
+pub fn devices() -> Vec<String> {
+	let _lock = enumeration();
 
 	unsafe {
 		if extension::device::is_supported("ALC_ENUMERATION_EXT") {
-			let mut ptr = alcGetString(ptr::null(), ALC_CAPTURE_DEVICE_SPECIFIER);
-
-			while *ptr != 0 {
-				result.push(from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes()));
-
-				ptr = ptr.offset(strlen(ptr) as isize + 1);
-			}
+			strings(alcGetString(ptr::null_mut(), ALC_CAPTURE_DEVICE_SPECIFIER))
+		}
+		else {
+			Vec::new()
 		}
 	}
+}
+
+/// Gets the name of the default capture device.
+pub fn default_device() -> Option<String> {
+	let _lock = enumeration();
 
-	result
+	unsafe {
+		string(alcGetString(ptr::null_mut(), ALC_CAPTURE_DEFAULT_DEVICE_SPECIFIER))
+	}
 }
@@ -0,0 +1,99 @@
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use {Error, Sample, Capture};
+use super::Frames;
+
+/// A `Capture` recording on a background thread.
+///
+/// The thread starts the device, delivers chunks of a fixed number of frames
+/// and stops on the first error, which is returned by `stop`.
+pub struct Recorder<T: Sample + Send + 'static> {
+	stop:   Arc<AtomicBool>,
+	thread: Option<JoinHandle<Result<Capture<T>, Error>>>,
+}
+
+impl<T: Sample + Send + 'static> Recorder<T> {
+	#[doc(hidden)]
+	pub fn new<F>(mut capture: Capture<T>, frames: usize, interval: Duration, mut callback: F) -> Self
+		where F: FnMut(Frames<T>) + Send + 'static
+	{
+		let stop   = Arc::new(AtomicBool::new(false));
+		let frames = frames.max(1);
+
+		let thread = {
+			let stop = stop.clone();
+
+			thread::spawn(move || {
+				capture.start();
+
+				while !stop.load(Ordering::Relaxed) {
+					while capture.len() >= frames {
+						match capture.take_frames(frames) {
+							Ok(chunk) =>
+								callback(chunk),
+
+							Err(error) => {
+								capture.stop();
+
+								return Err(error);
+							}
+						}
+					}
+
+					thread::sleep(interval);
+				}
+
+				capture.stop();
+
+				Ok(capture)
+			})
+		};
+
+		Recorder {
+			stop:   stop,
+			thread: Some(thread),
+		}
+	}
+}
+
+impl<T: Sample + Send + 'static> Recorder<T> {
+	/// Checks if the background thread is still recording.
+	pub fn is_running(&self) -> bool {
+		self.thread.as_ref().map_or(false, |thread| !thread.is_finished())
+	}
+
+	/// Stops the background thread and returns the `Capture`, or the error
+	/// that stopped the thread.
+	pub fn stop(mut self) -> Result<Capture<T>, Error> {
+		self.stop.store(true, Ordering::Relaxed);
+
+		match self.thread.take().unwrap().join() {
+			Ok(result) =>
+				result,
+
+			Err(error) =>
+				::std::panic::resume_unwind(error)
+		}
+	}
+}
+
+impl<T: Sample + Send + 'static> ::std::fmt::Debug for Recorder<T> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::capture::Recorder("));
+		try!(f.write_str(&format!("running={}", self.is_running())));
+		f.write_str(")")
+	}
+}
+
+impl<T: Sample + Send + 'static> Drop for Recorder<T> {
+	fn drop(&mut self) {
+		self.stop.store(true, Ordering::Relaxed);
+
+		if let Some(thread) = self.thread.take() {
+			thread.join().ok();
+		}
+	}
+}
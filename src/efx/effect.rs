@@ -0,0 +1,221 @@
+use std::marker::PhantomData;
+
+use ffi::*;
+use sys::*;
+use listener::Current;
+use Error;
+use super::{Reverb, EaxReverb};
+
+/// Represents the kind of an `Effect`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum EffectKind {
+	/// No effect.
+	Null,
+
+	/// Standard reverb.
+	Reverb,
+
+	/// EAX reverb.
+	EaxReverb,
+
+	/// Chorus.
+	Chorus,
+
+	/// Distortion.
+	Distortion,
+
+	/// Echo.
+	Echo,
+
+	/// Flanger.
+	Flanger,
+
+	/// Frequency shifter.
+	FrequencyShifter,
+
+	/// Vocal morpher.
+	VocalMorpher,
+
+	/// Pitch shifter.
+	PitchShifter,
+
+	/// Ring modulator.
+	RingModulator,
+
+	/// Auto-wah.
+	Autowah,
+
+	/// Compressor.
+	Compressor,
+
+	/// Equalizer.
+	Equalizer,
+}
+
+impl From<ALenum> for EffectKind {
+	fn from(value: ALenum) -> EffectKind {
+		match value {
+			AL_EFFECT_REVERB            => EffectKind::Reverb,
+			AL_EFFECT_EAXREVERB         => EffectKind::EaxReverb,
+			AL_EFFECT_CHORUS            => EffectKind::Chorus,
+			AL_EFFECT_DISTORTION        => EffectKind::Distortion,
+			AL_EFFECT_ECHO              => EffectKind::Echo,
+			AL_EFFECT_FLANGER           => EffectKind::Flanger,
+			AL_EFFECT_FREQUENCY_SHIFTER => EffectKind::FrequencyShifter,
+			AL_EFFECT_VOCAL_MORPHER     => EffectKind::VocalMorpher,
+			AL_EFFECT_PITCH_SHIFTER     => EffectKind::PitchShifter,
+			AL_EFFECT_RING_MODULATOR    => EffectKind::RingModulator,
+			AL_EFFECT_AUTOWAH           => EffectKind::Autowah,
+			AL_EFFECT_COMPRESSOR        => EffectKind::Compressor,
+			AL_EFFECT_EQUALIZER         => EffectKind::Equalizer,
+
+			_ => EffectKind::Null,
+		}
+	}
+}
+
+impl From<EffectKind> for ALenum {
+	fn from(value: EffectKind) -> ALenum {
+		match value {
+			EffectKind::Null             => AL_EFFECT_NULL,
+			EffectKind::Reverb           => AL_EFFECT_REVERB,
+			EffectKind::EaxReverb        => AL_EFFECT_EAXREVERB,
+			EffectKind::Chorus           => AL_EFFECT_CHORUS,
+			EffectKind::Distortion       => AL_EFFECT_DISTORTION,
+			EffectKind::Echo             => AL_EFFECT_ECHO,
+			EffectKind::Flanger          => AL_EFFECT_FLANGER,
+			EffectKind::FrequencyShifter => AL_EFFECT_FREQUENCY_SHIFTER,
+			EffectKind::VocalMorpher     => AL_EFFECT_VOCAL_MORPHER,
+			EffectKind::PitchShifter     => AL_EFFECT_PITCH_SHIFTER,
+			EffectKind::RingModulator    => AL_EFFECT_RING_MODULATOR,
+			EffectKind::Autowah          => AL_EFFECT_AUTOWAH,
+			EffectKind::Compressor       => AL_EFFECT_COMPRESSOR,
+			EffectKind::Equalizer        => AL_EFFECT_EQUALIZER,
+		}
+	}
+}
+
+/// A trait for the typed parameters of an effect kind.
+pub trait EffectProperties {
+	/// The kind of effect the parameters are for.
+	fn kind() -> EffectKind;
+
+	/// Checks all parameters are within their valid range.
+	fn validate(&self) -> Result<(), Error>;
+
+	#[doc(hidden)]
+	unsafe fn apply(&self, effect: ALuint) -> Result<(), Error>;
+}
+
+/// An effect object.
+///
+/// Effects hold the parameters for an effect kind, they're used by loading
+/// them into an `EffectSlot`.
+#[derive(PartialEq, Eq)]
+pub struct Effect<'a> {
+	id:      ALuint,
+	context: *mut ALCcontext,
+
+	_marker: PhantomData<&'a ()>,
+}
+
+unsafe impl<'a> Send for Effect<'a> { }
+unsafe impl<'a> Sync for Effect<'a> { }
+
+impl<'a> Effect<'a> {
+	#[doc(hidden)]
+	pub unsafe fn new() -> Result<Self, Error> {
+		let mut id = 0;
+		al_try!(alGenEffects(1, &mut id));
+
+		Ok(Effect { id: id, context: Current::get(), _marker: PhantomData })
+	}
+
+	#[doc(hidden)]
+	pub unsafe fn id(&self) -> ALuint {
+		self.id
+	}
+
+	// Make the owning context current for the duration of a call, effect names
+	// are only valid on the device they were created on.
+	fn scoped(&self) -> Result<Option<Current>, Error> {
+		Current::scope(self.context)
+	}
+}
+
+impl<'a> Effect<'a> {
+	/// Gets the kind.
+	pub fn kind(&self) -> EffectKind {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = 0;
+			alGetEffecti(self.id, AL_EFFECT_TYPE, &mut value);
+
+			EffectKind::from(value)
+		}
+	}
+
+	/// Sets the kind, resetting all parameters to their defaults.
+	pub fn set_kind(&mut self, kind: EffectKind) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			al_try!(alEffecti(self.id, AL_EFFECT_TYPE, kind.into()));
+		}
+
+		Ok(())
+	}
+
+	/// Loads the given parameters, changing the kind if needed.
+	pub fn set<T: EffectProperties>(&mut self, value: &T) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		try!(value.validate());
+
+		if self.kind() != T::kind() {
+			try!(self.set_kind(T::kind()));
+		}
+
+		unsafe {
+			value.apply(self.id)
+		}
+	}
+
+	/// Loads the given reverb parameters, falling back to the standard reverb
+	/// when the EAX reverb is not available.
+	pub fn set_reverb(&mut self, value: &EaxReverb) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		try!(value.validate());
+
+		if self.kind() == EffectKind::EaxReverb || self.set_kind(EffectKind::EaxReverb).is_ok() {
+			unsafe {
+				value.apply(self.id)
+			}
+		}
+		else {
+			self.set(&Reverb::from(value))
+		}
+	}
+}
+
+impl<'a> ::std::fmt::Debug for Effect<'a> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::efx::Effect("));
+		try!(f.write_str(&format!("{}; ", unsafe { self.id() })));
+		try!(f.write_str(&format!("kind={:?}", self.kind())));
+		f.write_str(")")
+	}
+}
+
+impl<'a> Drop for Effect<'a> {
+	fn drop(&mut self) {
+		let _current = self.scoped();
+
+		unsafe {
+			alDeleteEffects(1, &self.id);
+			al_panic!();
+		}
+	}
+}
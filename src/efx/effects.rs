@@ -0,0 +1,519 @@
+use std::mem;
+
+use ffi::*;
+use sys::*;
+use {Error, Vector};
+use super::{EffectKind, EffectProperties};
+
+trait Value: Copy {
+	fn is_within(&self, _min: Self, _max: Self) -> bool {
+		true
+	}
+
+	unsafe fn apply(&self, effect: ALuint, param: ALenum);
+}
+
+impl Value for f32 {
+	fn is_within(&self, min: Self, max: Self) -> bool {
+		min <= *self && *self <= max
+	}
+
+	unsafe fn apply(&self, effect: ALuint, param: ALenum) {
+		alEffectf(effect, param, *self as ALfloat);
+	}
+}
+
+impl Value for i32 {
+	fn is_within(&self, min: Self, max: Self) -> bool {
+		min <= *self && *self <= max
+	}
+
+	unsafe fn apply(&self, effect: ALuint, param: ALenum) {
+		alEffecti(effect, param, *self as ALint);
+	}
+}
+
+impl Value for bool {
+	unsafe fn apply(&self, effect: ALuint, param: ALenum) {
+		alEffecti(effect, param, if *self { AL_TRUE } else { AL_FALSE } as ALint);
+	}
+}
+
+impl Value for Vector {
+	fn is_within(&self, min: Self, max: Self) -> bool {
+		self.x.is_within(min.x, max.x) &&
+		self.y.is_within(min.y, max.y) &&
+		self.z.is_within(min.z, max.z)
+	}
+
+	unsafe fn apply(&self, effect: ALuint, param: ALenum) {
+		alEffectfv(effect, param, mem::transmute(self));
+	}
+}
+
+macro_rules! value {
+	($($name:ident),*) => ($(
+		impl Value for $name {
+			unsafe fn apply(&self, effect: ALuint, param: ALenum) {
+				alEffecti(effect, param, *self as ALint);
+			}
+		}
+	)*)
+}
+
+macro_rules! properties {
+	($(#[$attr:meta])* pub struct $name:ident: $kind:ident {
+		$($(#[$field_attr:meta])* pub $field:ident: $ty:ty = $param:ident, $default:expr $(, $min:expr, $max:expr)*;)*
+	}) => (
+		$(#[$attr])*
+		#[derive(PartialEq, Copy, Clone, Debug)]
+		pub struct $name {
+			$($(#[$field_attr])* pub $field: $ty,)*
+		}
+
+		impl Default for $name {
+			fn default() -> Self {
+				$name {
+					$($field: $default,)*
+				}
+			}
+		}
+
+		impl EffectProperties for $name {
+			fn kind() -> EffectKind {
+				EffectKind::$kind
+			}
+
+			fn validate(&self) -> Result<(), Error> {
+				$($(
+					if !self.$field.is_within($min, $max) {
+						return Err(Error::InvalidValue);
+					}
+				)*)*
+
+				Ok(())
+			}
+
+			unsafe fn apply(&self, effect: ALuint) -> Result<(), Error> {
+				$(al_try!(self.$field.apply(effect, $param));)*
+
+				Ok(())
+			}
+		}
+	)
+}
+
+const PAN:     Vector = Vector { x:  0.0, y:  0.0, z:  0.0 };
+const PAN_MIN: Vector = Vector { x: -1.0, y: -1.0, z: -1.0 };
+const PAN_MAX: Vector = Vector { x:  1.0, y:  1.0, z:  1.0 };
+
+/// The waveform of the low frequency oscillator used by `Chorus` and
+/// `Flanger`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Waveform {
+	/// Sinusoid.
+	Sinusoid = 0,
+
+	/// Triangle.
+	Triangle = 1,
+}
+
+/// The waveform of the low frequency oscillator used by `VocalMorpher`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum MorpherWaveform {
+	/// Sinusoid.
+	Sinusoid = 0,
+
+	/// Triangle.
+	Triangle = 1,
+
+	/// Sawtooth.
+	Sawtooth = 2,
+}
+
+/// The waveform of the carrier signal used by `RingModulator`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ModulatorWaveform {
+	/// Sinusoid.
+	Sinusoid = 0,
+
+	/// Sawtooth.
+	Sawtooth = 1,
+
+	/// Square.
+	Square = 2,
+}
+
+/// The direction of a channel of the `FrequencyShifter`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ShiftDirection {
+	/// Shift the frequency down.
+	Down = 0,
+
+	/// Shift the frequency up.
+	Up = 1,
+
+	/// Don't shift the frequency.
+	Off = 2,
+}
+
+/// The phonemes used by `VocalMorpher`.
+#[allow(missing_docs)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Phoneme {
+	A, E, I, O, U,
+	AA, AE, AH, AO, EH, ER, IH, IY, UH, UW,
+	B, D, F, G, J, K, L, M, N, P, R, S, T, V, Z,
+}
+
+value!(Waveform, MorpherWaveform, ModulatorWaveform, ShiftDirection, Phoneme);
+
+properties! {
+	/// Parameters for the standard reverb.
+	pub struct Reverb: Reverb {
+		/// Modal density of the reverb decay.
+		pub density: f32 = AL_REVERB_DENSITY, 1.0, 0.0, 1.0;
+
+		/// Echo density of the reverb decay.
+		pub diffusion: f32 = AL_REVERB_DIFFUSION, 1.0, 0.0, 1.0;
+
+		/// Master volume of the reflected sound.
+		pub gain: f32 = AL_REVERB_GAIN, 0.32, 0.0, 1.0;
+
+		/// Attenuation at high frequencies.
+		pub gain_hf: f32 = AL_REVERB_GAINHF, 0.89, 0.0, 1.0;
+
+		/// Reverberation decay time, in seconds.
+		pub decay_time: f32 = AL_REVERB_DECAY_TIME, 1.49, 0.1, 20.0;
+
+		/// Ratio of high frequency decay time to mid frequency decay time.
+		pub decay_hf_ratio: f32 = AL_REVERB_DECAY_HFRATIO, 0.83, 0.1, 2.0;
+
+		/// Volume of the initial reflections.
+		pub reflections_gain: f32 = AL_REVERB_REFLECTIONS_GAIN, 0.05, 0.0, 3.16;
+
+		/// Delay of the initial reflections, in seconds.
+		pub reflections_delay: f32 = AL_REVERB_REFLECTIONS_DELAY, 0.007, 0.0, 0.3;
+
+		/// Volume of the late reverberation.
+		pub late_reverb_gain: f32 = AL_REVERB_LATE_REVERB_GAIN, 1.26, 0.0, 10.0;
+
+		/// Delay of the late reverberation after the initial reflections, in
+		/// seconds.
+		pub late_reverb_delay: f32 = AL_REVERB_LATE_REVERB_DELAY, 0.011, 0.0, 0.1;
+
+		/// High frequency attenuation per meter caused by the air.
+		pub air_absorption_gain_hf: f32 = AL_REVERB_AIR_ABSORPTION_GAINHF, 0.994, 0.892, 1.0;
+
+		/// Rolloff factor applied to the reflected sound.
+		pub room_rolloff_factor: f32 = AL_REVERB_ROOM_ROLLOFF_FACTOR, 0.0, 0.0, 10.0;
+
+		/// Whether the high frequency decay time is limited by the air
+		/// absorption.
+		pub decay_hf_limit: bool = AL_REVERB_DECAY_HFLIMIT, true;
+	}
+}
+
+properties! {
+	/// Parameters for the EAX reverb.
+	pub struct EaxReverb: EaxReverb {
+		/// Modal density of the reverb decay.
+		pub density: f32 = AL_EAXREVERB_DENSITY, 1.0, 0.0, 1.0;
+
+		/// Echo density of the reverb decay.
+		pub diffusion: f32 = AL_EAXREVERB_DIFFUSION, 1.0, 0.0, 1.0;
+
+		/// Master volume of the reflected sound.
+		pub gain: f32 = AL_EAXREVERB_GAIN, 0.32, 0.0, 1.0;
+
+		/// Attenuation at high frequencies.
+		pub gain_hf: f32 = AL_EAXREVERB_GAINHF, 0.89, 0.0, 1.0;
+
+		/// Attenuation at low frequencies.
+		pub gain_lf: f32 = AL_EAXREVERB_GAINLF, 1.0, 0.0, 1.0;
+
+		/// Reverberation decay time, in seconds.
+		pub decay_time: f32 = AL_EAXREVERB_DECAY_TIME, 1.49, 0.1, 20.0;
+
+		/// Ratio of high frequency decay time to mid frequency decay time.
+		pub decay_hf_ratio: f32 = AL_EAXREVERB_DECAY_HFRATIO, 0.83, 0.1, 2.0;
+
+		/// Ratio of low frequency decay time to mid frequency decay time.
+		pub decay_lf_ratio: f32 = AL_EAXREVERB_DECAY_LFRATIO, 1.0, 0.1, 2.0;
+
+		/// Volume of the initial reflections.
+		pub reflections_gain: f32 = AL_EAXREVERB_REFLECTIONS_GAIN, 0.05, 0.0, 3.16;
+
+		/// Delay of the initial reflections, in seconds.
+		pub reflections_delay: f32 = AL_EAXREVERB_REFLECTIONS_DELAY, 0.007, 0.0, 0.3;
+
+		/// Direction of the initial reflections.
+		pub reflections_pan: Vector = AL_EAXREVERB_REFLECTIONS_PAN, PAN, PAN_MIN, PAN_MAX;
+
+		/// Volume of the late reverberation.
+		pub late_reverb_gain: f32 = AL_EAXREVERB_LATE_REVERB_GAIN, 1.26, 0.0, 10.0;
+
+		/// Delay of the late reverberation after the initial reflections, in
+		/// seconds.
+		pub late_reverb_delay: f32 = AL_EAXREVERB_LATE_REVERB_DELAY, 0.011, 0.0, 0.1;
+
+		/// Direction of the late reverberation.
+		pub late_reverb_pan: Vector = AL_EAXREVERB_LATE_REVERB_PAN, PAN, PAN_MIN, PAN_MAX;
+
+		/// Rate of the cyclic echo in the reverberation, in seconds.
+		pub echo_time: f32 = AL_EAXREVERB_ECHO_TIME, 0.25, 0.075, 0.25;
+
+		/// Depth of the cyclic echo in the reverberation.
+		pub echo_depth: f32 = AL_EAXREVERB_ECHO_DEPTH, 0.0, 0.0, 1.0;
+
+		/// Rate of the pitch modulation of the reverberation, in seconds.
+		pub modulation_time: f32 = AL_EAXREVERB_MODULATION_TIME, 0.25, 0.04, 4.0;
+
+		/// Depth of the pitch modulation of the reverberation.
+		pub modulation_depth: f32 = AL_EAXREVERB_MODULATION_DEPTH, 0.0, 0.0, 1.0;
+
+		/// High frequency attenuation per meter caused by the air.
+		pub air_absorption_gain_hf: f32 = AL_EAXREVERB_AIR_ABSORPTION_GAINHF, 0.994, 0.892, 1.0;
+
+		/// Reference frequency for the high frequency parameters, in Hz.
+		pub hf_reference: f32 = AL_EAXREVERB_HFREFERENCE, 5000.0, 1000.0, 20000.0;
+
+		/// Reference frequency for the low frequency parameters, in Hz.
+		pub lf_reference: f32 = AL_EAXREVERB_LFREFERENCE, 250.0, 20.0, 1000.0;
+
+		/// Rolloff factor applied to the reflected sound.
+		pub room_rolloff_factor: f32 = AL_EAXREVERB_ROOM_ROLLOFF_FACTOR, 0.0, 0.0, 10.0;
+
+		/// Whether the high frequency decay time is limited by the air
+		/// absorption.
+		pub decay_hf_limit: bool = AL_EAXREVERB_DECAY_HFLIMIT, true;
+	}
+}
+
+impl<'a> From<&'a EaxReverb> for Reverb {
+	fn from(value: &'a EaxReverb) -> Reverb {
+		Reverb {
+			density:                value.density,
+			diffusion:              value.diffusion,
+			gain:                   value.gain,
+			gain_hf:                value.gain_hf,
+			decay_time:             value.decay_time,
+			decay_hf_ratio:         value.decay_hf_ratio,
+			reflections_gain:       value.reflections_gain,
+			reflections_delay:      value.reflections_delay,
+			late_reverb_gain:       value.late_reverb_gain,
+			late_reverb_delay:      value.late_reverb_delay,
+			air_absorption_gain_hf: value.air_absorption_gain_hf,
+			room_rolloff_factor:    value.room_rolloff_factor,
+			decay_hf_limit:         value.decay_hf_limit,
+		}
+	}
+}
+
+properties! {
+	/// Parameters for the chorus.
+	pub struct Chorus: Chorus {
+		/// Waveform of the low frequency oscillator.
+		pub waveform: Waveform = AL_CHORUS_WAVEFORM, Waveform::Triangle;
+
+		/// Phase difference between the left and right oscillators, in degrees.
+		pub phase: i32 = AL_CHORUS_PHASE, 90, -180, 180;
+
+		/// Modulation rate, in Hz.
+		pub rate: f32 = AL_CHORUS_RATE, 1.1, 0.0, 10.0;
+
+		/// Modulation depth.
+		pub depth: f32 = AL_CHORUS_DEPTH, 0.1, 0.0, 1.0;
+
+		/// Amount of output fed back into the input.
+		pub feedback: f32 = AL_CHORUS_FEEDBACK, 0.25, -1.0, 1.0;
+
+		/// Average delay, in seconds.
+		pub delay: f32 = AL_CHORUS_DELAY, 0.016, 0.0, 0.016;
+	}
+}
+
+properties! {
+	/// Parameters for the distortion.
+	pub struct Distortion: Distortion {
+		/// Shape of the distortion.
+		pub edge: f32 = AL_DISTORTION_EDGE, 0.2, 0.0, 1.0;
+
+		/// Volume of the output.
+		pub gain: f32 = AL_DISTORTION_GAIN, 0.05, 0.01, 1.0;
+
+		/// Cutoff of the lowpass filter applied before the distortion, in Hz.
+		pub lowpass_cutoff: f32 = AL_DISTORTION_LOWPASS_CUTOFF, 8000.0, 80.0, 24000.0;
+
+		/// Center of the post-distortion equalizer band, in Hz.
+		pub eq_center: f32 = AL_DISTORTION_EQCENTER, 3600.0, 80.0, 24000.0;
+
+		/// Width of the post-distortion equalizer band, in Hz.
+		pub eq_bandwidth: f32 = AL_DISTORTION_EQBANDWIDTH, 3600.0, 80.0, 24000.0;
+	}
+}
+
+properties! {
+	/// Parameters for the echo.
+	pub struct Echo: Echo {
+		/// Delay of the first echo, in seconds.
+		pub delay: f32 = AL_ECHO_DELAY, 0.1, 0.0, 0.207;
+
+		/// Delay between the first and second echo, in seconds.
+		pub lr_delay: f32 = AL_ECHO_LRDELAY, 0.1, 0.0, 0.404;
+
+		/// Amount of high frequency damping applied to each echo.
+		pub damping: f32 = AL_ECHO_DAMPING, 0.5, 0.0, 0.99;
+
+		/// Amount of output fed back into the input.
+		pub feedback: f32 = AL_ECHO_FEEDBACK, 0.5, 0.0, 1.0;
+
+		/// Stereo spread of the echoes.
+		pub spread: f32 = AL_ECHO_SPREAD, -1.0, -1.0, 1.0;
+	}
+}
+
+properties! {
+	/// Parameters for the flanger.
+	pub struct Flanger: Flanger {
+		/// Waveform of the low frequency oscillator.
+		pub waveform: Waveform = AL_FLANGER_WAVEFORM, Waveform::Triangle;
+
+		/// Phase difference between the left and right oscillators, in degrees.
+		pub phase: i32 = AL_FLANGER_PHASE, 0, -180, 180;
+
+		/// Modulation rate, in Hz.
+		pub rate: f32 = AL_FLANGER_RATE, 0.27, 0.0, 10.0;
+
+		/// Modulation depth.
+		pub depth: f32 = AL_FLANGER_DEPTH, 1.0, 0.0, 1.0;
+
+		/// Amount of output fed back into the input.
+		pub feedback: f32 = AL_FLANGER_FEEDBACK, -0.5, -1.0, 1.0;
+
+		/// Average delay, in seconds.
+		pub delay: f32 = AL_FLANGER_DELAY, 0.002, 0.0, 0.004;
+	}
+}
+
+properties! {
+	/// Parameters for the frequency shifter.
+	pub struct FrequencyShifter: FrequencyShifter {
+		/// Amount of frequency shift, in Hz.
+		pub frequency: f32 = AL_FREQUENCY_SHIFTER_FREQUENCY, 0.0, 0.0, 24000.0;
+
+		/// Direction of the shift on the left channel.
+		pub left_direction: ShiftDirection = AL_FREQUENCY_SHIFTER_LEFT_DIRECTION, ShiftDirection::Down;
+
+		/// Direction of the shift on the right channel.
+		pub right_direction: ShiftDirection = AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION, ShiftDirection::Down;
+	}
+}
+
+properties! {
+	/// Parameters for the vocal morpher.
+	pub struct VocalMorpher: VocalMorpher {
+		/// The first phoneme.
+		pub phoneme_a: Phoneme = AL_VOCAL_MORPHER_PHONEMEA, Phoneme::A;
+
+		/// Coarse tuning of the first phoneme, in semitones.
+		pub phoneme_a_coarse_tuning: i32 = AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING, 0, -24, 24;
+
+		/// The second phoneme.
+		pub phoneme_b: Phoneme = AL_VOCAL_MORPHER_PHONEMEB, Phoneme::ER;
+
+		/// Coarse tuning of the second phoneme, in semitones.
+		pub phoneme_b_coarse_tuning: i32 = AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING, 0, -24, 24;
+
+		/// Waveform of the low frequency oscillator morphing between phonemes.
+		pub waveform: MorpherWaveform = AL_VOCAL_MORPHER_WAVEFORM, MorpherWaveform::Sinusoid;
+
+		/// Morphing rate, in Hz.
+		pub rate: f32 = AL_VOCAL_MORPHER_RATE, 1.41, 0.0, 10.0;
+	}
+}
+
+properties! {
+	/// Parameters for the pitch shifter.
+	pub struct PitchShifter: PitchShifter {
+		/// Coarse shift, in semitones.
+		pub coarse_tune: i32 = AL_PITCH_SHIFTER_COARSE_TUNE, 12, -12, 12;
+
+		/// Fine shift, in cents.
+		pub fine_tune: i32 = AL_PITCH_SHIFTER_FINE_TUNE, 0, -50, 50;
+	}
+}
+
+properties! {
+	/// Parameters for the ring modulator.
+	pub struct RingModulator: RingModulator {
+		/// Frequency of the carrier signal, in Hz.
+		pub frequency: f32 = AL_RING_MODULATOR_FREQUENCY, 440.0, 0.0, 8000.0;
+
+		/// Cutoff of the highpass filter applied to the input, in Hz.
+		pub highpass_cutoff: f32 = AL_RING_MODULATOR_HIGHPASS_CUTOFF, 800.0, 0.0, 24000.0;
+
+		/// Waveform of the carrier signal.
+		pub waveform: ModulatorWaveform = AL_RING_MODULATOR_WAVEFORM, ModulatorWaveform::Sinusoid;
+	}
+}
+
+properties! {
+	/// Parameters for the auto-wah.
+	pub struct Autowah: Autowah {
+		/// Attack time of the envelope follower, in seconds.
+		pub attack_time: f32 = AL_AUTOWAH_ATTACK_TIME, 0.06, 0.0001, 1.0;
+
+		/// Release time of the envelope follower, in seconds.
+		pub release_time: f32 = AL_AUTOWAH_RELEASE_TIME, 0.06, 0.0001, 1.0;
+
+		/// Resonance of the filter.
+		pub resonance: f32 = AL_AUTOWAH_RESONANCE, 1000.0, 2.0, 1000.0;
+
+		/// Peak gain of the filter.
+		pub peak_gain: f32 = AL_AUTOWAH_PEAK_GAIN, 11.22, 0.00003, 31621.0;
+	}
+}
+
+properties! {
+	/// Parameters for the compressor.
+	pub struct Compressor: Compressor {
+		/// Whether the compressor is enabled.
+		pub enabled: bool = AL_COMPRESSOR_ONOFF, true;
+	}
+}
+
+properties! {
+	/// Parameters for the equalizer.
+	pub struct Equalizer: Equalizer {
+		/// Gain of the low band.
+		pub low_gain: f32 = AL_EQUALIZER_LOW_GAIN, 1.0, 0.126, 7.943;
+
+		/// Cutoff of the low band, in Hz.
+		pub low_cutoff: f32 = AL_EQUALIZER_LOW_CUTOFF, 200.0, 50.0, 800.0;
+
+		/// Gain of the first mid band.
+		pub mid1_gain: f32 = AL_EQUALIZER_MID1_GAIN, 1.0, 0.126, 7.943;
+
+		/// Center of the first mid band, in Hz.
+		pub mid1_center: f32 = AL_EQUALIZER_MID1_CENTER, 500.0, 200.0, 3000.0;
+
+		/// Width of the first mid band.
+		pub mid1_width: f32 = AL_EQUALIZER_MID1_WIDTH, 1.0, 0.01, 1.0;
+
+		/// Gain of the second mid band.
+		pub mid2_gain: f32 = AL_EQUALIZER_MID2_GAIN, 1.0, 0.126, 7.943;
+
+		/// Center of the second mid band, in Hz.
+		pub mid2_center: f32 = AL_EQUALIZER_MID2_CENTER, 3000.0, 1000.0, 8000.0;
+
+		/// Width of the second mid band.
+		pub mid2_width: f32 = AL_EQUALIZER_MID2_WIDTH, 1.0, 0.01, 1.0;
+
+		/// Gain of the high band.
+		pub high_gain: f32 = AL_EQUALIZER_HIGH_GAIN, 1.0, 0.126, 7.943;
+
+		/// Cutoff of the high band, in Hz.
+		pub high_cutoff: f32 = AL_EQUALIZER_HIGH_CUTOFF, 6000.0, 4000.0, 16000.0;
+	}
+}
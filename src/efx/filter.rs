@@ -0,0 +1,235 @@
+use std::marker::PhantomData;
+
+use ffi::*;
+use sys::*;
+use listener::Current;
+use Error;
+
+/// Represents the kind of a `Filter`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum FilterKind {
+	/// No filter.
+	Null,
+
+	/// Lowpass filter.
+	Lowpass,
+
+	/// Highpass filter.
+	Highpass,
+
+	/// Bandpass filter.
+	Bandpass,
+}
+
+impl From<ALenum> for FilterKind {
+	fn from(value: ALenum) -> FilterKind {
+		match value {
+			AL_FILTER_LOWPASS  => FilterKind::Lowpass,
+			AL_FILTER_HIGHPASS => FilterKind::Highpass,
+			AL_FILTER_BANDPASS => FilterKind::Bandpass,
+
+			_ => FilterKind::Null,
+		}
+	}
+}
+
+impl From<FilterKind> for ALenum {
+	fn from(value: FilterKind) -> ALenum {
+		match value {
+			FilterKind::Null     => AL_FILTER_NULL,
+			FilterKind::Lowpass  => AL_FILTER_LOWPASS,
+			FilterKind::Highpass => AL_FILTER_HIGHPASS,
+			FilterKind::Bandpass => AL_FILTER_BANDPASS,
+		}
+	}
+}
+
+/// A trait for the typed parameters of a filter kind.
+pub trait FilterProperties {
+	/// The kind of filter the parameters are for.
+	fn kind() -> FilterKind;
+
+	/// Checks all parameters are within their valid range.
+	fn validate(&self) -> Result<(), Error>;
+
+	#[doc(hidden)]
+	unsafe fn apply(&self, filter: ALuint) -> Result<(), Error>;
+}
+
+macro_rules! properties {
+	($(#[$attr:meta])* pub struct $name:ident: $kind:ident {
+		$($(#[$field_attr:meta])* pub $field:ident = $param:ident;)*
+	}) => (
+		$(#[$attr])*
+		#[derive(PartialEq, Copy, Clone, Debug)]
+		pub struct $name {
+			$($(#[$field_attr])* pub $field: f32,)*
+		}
+
+		impl Default for $name {
+			fn default() -> Self {
+				$name {
+					$($field: 1.0,)*
+				}
+			}
+		}
+
+		impl FilterProperties for $name {
+			fn kind() -> FilterKind {
+				FilterKind::$kind
+			}
+
+			fn validate(&self) -> Result<(), Error> {
+				$(
+					if !(0.0 <= self.$field && self.$field <= 1.0) {
+						return Err(Error::InvalidValue);
+					}
+				)*
+
+				Ok(())
+			}
+
+			unsafe fn apply(&self, filter: ALuint) -> Result<(), Error> {
+				$(al_try!(alFilterf(filter, $param, self.$field as ALfloat));)*
+
+				Ok(())
+			}
+		}
+	)
+}
+
+properties! {
+	/// Parameters for the lowpass filter, all gains must be between 0.0 and
+	/// 1.0.
+	pub struct Lowpass: Lowpass {
+		/// Overall gain.
+		pub gain = AL_LOWPASS_GAIN;
+
+		/// Gain at high frequencies.
+		pub gain_hf = AL_LOWPASS_GAINHF;
+	}
+}
+
+properties! {
+	/// Parameters for the highpass filter, all gains must be between 0.0 and
+	/// 1.0.
+	pub struct Highpass: Highpass {
+		/// Overall gain.
+		pub gain = AL_HIGHPASS_GAIN;
+
+		/// Gain at low frequencies.
+		pub gain_lf = AL_HIGHPASS_GAINLF;
+	}
+}
+
+properties! {
+	/// Parameters for the bandpass filter, all gains must be between 0.0 and
+	/// 1.0.
+	pub struct Bandpass: Bandpass {
+		/// Overall gain.
+		pub gain = AL_BANDPASS_GAIN;
+
+		/// Gain at low frequencies.
+		pub gain_lf = AL_BANDPASS_GAINLF;
+
+		/// Gain at high frequencies.
+		pub gain_hf = AL_BANDPASS_GAINHF;
+	}
+}
+
+/// A filter object.
+///
+/// Filters are applied to the direct path of a `Source` or to its auxiliary
+/// sends, the parameters are copied when set, so changes to the `Filter` must
+/// be set again to be heard.
+#[derive(PartialEq, Eq)]
+pub struct Filter<'a> {
+	id:      ALuint,
+	context: *mut ALCcontext,
+
+	_marker: PhantomData<&'a ()>,
+}
+
+unsafe impl<'a> Send for Filter<'a> { }
+unsafe impl<'a> Sync for Filter<'a> { }
+
+impl<'a> Filter<'a> {
+	#[doc(hidden)]
+	pub unsafe fn new() -> Result<Self, Error> {
+		let mut id = 0;
+		al_try!(alGenFilters(1, &mut id));
+
+		Ok(Filter { id: id, context: Current::get(), _marker: PhantomData })
+	}
+
+	#[doc(hidden)]
+	pub unsafe fn id(&self) -> ALuint {
+		self.id
+	}
+
+	// Make the owning context current for the duration of a call, filter names
+	// are only valid on the device they were created on.
+	fn scoped(&self) -> Result<Option<Current>, Error> {
+		Current::scope(self.context)
+	}
+}
+
+impl<'a> Filter<'a> {
+	/// Gets the kind.
+	pub fn kind(&self) -> FilterKind {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = 0;
+			alGetFilteri(self.id, AL_FILTER_TYPE, &mut value);
+
+			FilterKind::from(value)
+		}
+	}
+
+	/// Sets the kind, resetting all parameters to their defaults.
+	pub fn set_kind(&mut self, kind: FilterKind) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			al_try!(alFilteri(self.id, AL_FILTER_TYPE, kind.into()));
+		}
+
+		Ok(())
+	}
+
+	/// Loads the given parameters, changing the kind if needed.
+	pub fn set<T: FilterProperties>(&mut self, value: &T) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		try!(value.validate());
+
+		if self.kind() != T::kind() {
+			try!(self.set_kind(T::kind()));
+		}
+
+		unsafe {
+			value.apply(self.id)
+		}
+	}
+}
+
+impl<'a> ::std::fmt::Debug for Filter<'a> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::efx::Filter("));
+		try!(f.write_str(&format!("{}; ", unsafe { self.id() })));
+		try!(f.write_str(&format!("kind={:?}", self.kind())));
+		f.write_str(")")
+	}
+}
+
+impl<'a> Drop for Filter<'a> {
+	fn drop(&mut self) {
+		let _current = self.scoped();
+
+		unsafe {
+			alDeleteFilters(1, &self.id);
+			al_panic!();
+		}
+	}
+}
@@ -0,0 +1,21 @@
+/*!
+Helpers related to the Effects Extension.
+
+Effects are loaded into an `EffectSlot`, which `Source`s can then send their
+output to, optionally through a `Filter`.
+*/
+
+mod effect;
+pub use self::effect::{Effect, EffectKind, EffectProperties};
+
+mod effects;
+pub use self::effects::{Reverb, EaxReverb, Chorus, Distortion, Echo, Flanger, FrequencyShifter, VocalMorpher, PitchShifter, RingModulator, Autowah, Compressor, Equalizer};
+pub use self::effects::{Waveform, MorpherWaveform, ModulatorWaveform, ShiftDirection, Phoneme};
+
+mod slot;
+pub use self::slot::EffectSlot;
+
+mod filter;
+pub use self::filter::{Filter, FilterKind, FilterProperties, Lowpass, Highpass, Bandpass};
+
+pub mod preset;
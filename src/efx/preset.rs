@@ -0,0 +1,123 @@
+/*!
+The standard EFX reverb presets.
+
+The presets are expressed as `EaxReverb` parameters, use `Effect::set_reverb`
+to load them so they fall back to the standard reverb when the EAX reverb is
+not available.
+*/
+
+use Vector;
+use super::EaxReverb;
+
+macro_rules! preset {
+	($(#[$attr:meta])* $name:ident => $density:expr, $diffusion:expr, $gain:expr, $gain_hf:expr, $gain_lf:expr,
+		$decay_time:expr, $decay_hf_ratio:expr, $decay_lf_ratio:expr, $reflections_gain:expr,
+		$reflections_delay:expr, $late_reverb_gain:expr, $late_reverb_delay:expr, $echo_time:expr,
+		$echo_depth:expr, $modulation_time:expr, $modulation_depth:expr, $air_absorption_gain_hf:expr,
+		$hf_reference:expr, $lf_reference:expr, $room_rolloff_factor:expr, $decay_hf_limit:expr) => (
+		$(#[$attr])*
+		pub const $name: EaxReverb = EaxReverb {
+			density:                $density,
+			diffusion:              $diffusion,
+			gain:                   $gain,
+			gain_hf:                $gain_hf,
+			gain_lf:                $gain_lf,
+			decay_time:             $decay_time,
+			decay_hf_ratio:         $decay_hf_ratio,
+			decay_lf_ratio:         $decay_lf_ratio,
+			reflections_gain:       $reflections_gain,
+			reflections_delay:      $reflections_delay,
+			reflections_pan:        Vector { x: 0.0, y: 0.0, z: 0.0 },
+			late_reverb_gain:       $late_reverb_gain,
+			late_reverb_delay:      $late_reverb_delay,
+			late_reverb_pan:        Vector { x: 0.0, y: 0.0, z: 0.0 },
+			echo_time:              $echo_time,
+			echo_depth:             $echo_depth,
+			modulation_time:        $modulation_time,
+			modulation_depth:       $modulation_depth,
+			air_absorption_gain_hf: $air_absorption_gain_hf,
+			hf_reference:           $hf_reference,
+			lf_reference:           $lf_reference,
+			room_rolloff_factor:    $room_rolloff_factor,
+			decay_hf_limit:         $decay_hf_limit,
+		};
+	)
+}
+
+preset!(/// Generic.
+	GENERIC => 1.0000, 1.0000, 0.3162, 0.8913, 1.0000, 1.4900, 0.8300, 1.0000, 0.0500, 0.0070, 1.2589, 0.0110, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Padded cell.
+	PADDED_CELL => 0.1715, 1.0000, 0.3162, 0.0010, 1.0000, 0.1700, 0.1000, 1.0000, 0.2500, 0.0010, 1.2691, 0.0020, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Room.
+	ROOM => 0.4287, 1.0000, 0.3162, 0.5929, 1.0000, 0.4000, 0.8300, 1.0000, 0.1503, 0.0020, 1.0629, 0.0030, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Bathroom.
+	BATHROOM => 0.1715, 1.0000, 0.3162, 0.2512, 1.0000, 1.4900, 0.5400, 1.0000, 0.6531, 0.0070, 3.2734, 0.0110, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Living room.
+	LIVING_ROOM => 0.9766, 1.0000, 0.3162, 0.0010, 1.0000, 0.5000, 0.1000, 1.0000, 0.2051, 0.0030, 0.2805, 0.0040, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Stone room.
+	STONE_ROOM => 1.0000, 1.0000, 0.3162, 0.7079, 1.0000, 2.3100, 0.6400, 1.0000, 0.4411, 0.0120, 1.1003, 0.0170, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Auditorium.
+	AUDITORIUM => 1.0000, 1.0000, 0.3162, 0.5781, 1.0000, 4.3200, 0.5900, 1.0000, 0.4032, 0.0200, 0.7170, 0.0300, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Concert hall.
+	CONCERT_HALL => 1.0000, 1.0000, 0.3162, 0.5623, 1.0000, 3.9200, 0.7000, 1.0000, 0.2427, 0.0200, 0.9977, 0.0290, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Cave.
+	CAVE => 1.0000, 1.0000, 0.3162, 1.0000, 1.0000, 2.9100, 1.3000, 1.0000, 0.5000, 0.0150, 0.7063, 0.0220, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, false);
+
+preset!(/// Arena.
+	ARENA => 1.0000, 1.0000, 0.3162, 0.4477, 1.0000, 7.2400, 0.3300, 1.0000, 0.2612, 0.0200, 1.0186, 0.0300, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Hangar.
+	HANGAR => 1.0000, 1.0000, 0.3162, 0.3162, 1.0000, 10.0500, 0.2300, 1.0000, 0.5000, 0.0200, 1.2560, 0.0300, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Carpeted hallway.
+	CARPETED_HALLWAY => 0.4287, 1.0000, 0.3162, 0.0100, 1.0000, 0.3000, 0.1000, 1.0000, 0.1215, 0.0020, 0.1531, 0.0300, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Hallway.
+	HALLWAY => 0.3645, 1.0000, 0.3162, 0.7079, 1.0000, 1.4900, 0.5900, 1.0000, 0.2458, 0.0070, 1.6615, 0.0110, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Stone corridor.
+	STONE_CORRIDOR => 1.0000, 1.0000, 0.3162, 0.7612, 1.0000, 2.7000, 0.7900, 1.0000, 0.2472, 0.0130, 1.5758, 0.0200, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Alley.
+	ALLEY => 1.0000, 0.3000, 0.3162, 0.7328, 1.0000, 1.4900, 0.8600, 1.0000, 0.2500, 0.0070, 0.9954, 0.0110, 0.1250, 0.9500, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Forest.
+	FOREST => 1.0000, 0.3000, 0.3162, 0.0224, 1.0000, 1.4900, 0.5400, 1.0000, 0.0525, 0.1620, 0.7682, 0.0880, 0.1250, 1.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// City.
+	CITY => 1.0000, 0.5000, 0.3162, 0.3981, 1.0000, 1.4900, 0.6700, 1.0000, 0.0730, 0.0070, 0.1427, 0.0110, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Mountains.
+	MOUNTAINS => 1.0000, 0.2700, 0.3162, 0.0562, 1.0000, 1.4900, 0.2100, 1.0000, 0.0407, 0.3000, 0.1919, 0.1000, 0.2500, 1.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, false);
+
+preset!(/// Quarry.
+	QUARRY => 1.0000, 1.0000, 0.3162, 0.3162, 1.0000, 1.4900, 0.8300, 1.0000, 0.0000, 0.0610, 1.7783, 0.0250, 0.1250, 0.7000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Plain.
+	PLAIN => 1.0000, 0.2100, 0.3162, 0.1000, 1.0000, 1.4900, 0.5000, 1.0000, 0.0585, 0.1790, 0.1089, 0.1000, 0.2500, 1.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Parking lot.
+	PARKING_LOT => 1.0000, 1.0000, 0.3162, 1.0000, 1.0000, 1.6500, 1.5000, 1.0000, 0.2082, 0.0080, 0.2652, 0.0120, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, false);
+
+preset!(/// Sewer pipe.
+	SEWER_PIPE => 0.3071, 0.8000, 0.3162, 0.3162, 1.0000, 2.8100, 0.1400, 1.0000, 1.6387, 0.0140, 3.2471, 0.0210, 0.2500, 0.0000, 0.2500, 0.0000, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Underwater.
+	UNDERWATER => 0.3645, 1.0000, 0.3162, 0.0100, 1.0000, 1.4900, 0.1000, 1.0000, 0.5963, 0.0070, 7.0795, 0.0110, 0.2500, 0.0000, 1.1800, 0.3480, 0.9943, 5000.0, 250.0, 0.0, true);
+
+preset!(/// Drugged.
+	DRUGGED => 0.4287, 0.5000, 0.3162, 1.0000, 1.0000, 8.3900, 1.3900, 1.0000, 0.8760, 0.0020, 3.1081, 0.0300, 0.2500, 0.0000, 0.2500, 1.0000, 0.9943, 5000.0, 250.0, 0.0, false);
+
+preset!(/// Dizzy.
+	DIZZY => 0.3645, 0.6000, 0.3162, 0.6310, 1.0000, 17.2300, 0.5600, 1.0000, 0.1392, 0.0200, 0.4937, 0.0300, 0.2500, 1.0000, 0.8100, 0.3100, 0.9943, 5000.0, 250.0, 0.0, false);
+
+preset!(/// Psychotic.
+	PSYCHOTIC => 0.0625, 0.5000, 0.3162, 0.8404, 1.0000, 7.5600, 0.9100, 1.0000, 0.4864, 0.0200, 2.4378, 0.0300, 0.2500, 0.0000, 4.0000, 1.0000, 0.9943, 5000.0, 250.0, 0.0, false);
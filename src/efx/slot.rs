@@ -0,0 +1,151 @@
+use std::marker::PhantomData;
+
+use ffi::*;
+use sys::*;
+use listener::Current;
+use Error;
+use super::Effect;
+
+/// An auxiliary effect slot.
+///
+/// Slots process the output sent to them by `Source`s through the `Effect`
+/// loaded into them.
+///
+/// Unlike other objects the setters take `&self`, since a `Source` keeps the
+/// slot borrowed for as long as it sends to it and the slot has to remain
+/// adjustable meanwhile.
+#[derive(PartialEq, Eq)]
+pub struct EffectSlot<'a> {
+	id:      ALuint,
+	context: *mut ALCcontext,
+
+	_marker: PhantomData<&'a ()>,
+}
+
+unsafe impl<'a> Send for EffectSlot<'a> { }
+unsafe impl<'a> Sync for EffectSlot<'a> { }
+
+impl<'a> EffectSlot<'a> {
+	#[doc(hidden)]
+	pub unsafe fn new() -> Result<Self, Error> {
+		let mut id = 0;
+		al_try!(alGenAuxiliaryEffectSlots(1, &mut id));
+
+		Ok(EffectSlot { id: id, context: Current::get(), _marker: PhantomData })
+	}
+
+	#[doc(hidden)]
+	pub unsafe fn id(&self) -> ALuint {
+		self.id
+	}
+
+	// Make the owning context current for the duration of a call, slot names
+	// are only valid on the context they were created on.
+	fn scoped(&self) -> Result<Option<Current>, Error> {
+		Current::scope(self.context)
+	}
+}
+
+impl<'a> EffectSlot<'a> {
+	/// Loads the effect into the slot.
+	///
+	/// The parameters are copied, so the `Effect` can be changed or dropped
+	/// afterwards without affecting the slot.
+	pub fn set_effect(&self, effect: &Effect) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			al_try!(alAuxiliaryEffectSloti(self.id, AL_EFFECTSLOT_EFFECT, effect.id() as ALint));
+		}
+
+		Ok(())
+	}
+
+	/// Removes the effect from the slot.
+	pub fn clear_effect(&self) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			al_try!(alAuxiliaryEffectSloti(self.id, AL_EFFECTSLOT_EFFECT, AL_EFFECTSLOT_NULL));
+		}
+
+		Ok(())
+	}
+
+	/// Gets the gain.
+	pub fn gain(&self) -> f32 {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = 0.0;
+			alGetAuxiliaryEffectSlotf(self.id, AL_EFFECTSLOT_GAIN, &mut value);
+
+			value as f32
+		}
+	}
+
+	/// Sets the gain, it must be between 0.0 and 1.0.
+	pub fn set_gain(&self, value: f32) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		if !(0.0 <= value && value <= 1.0) {
+			return Err(Error::InvalidValue);
+		}
+
+		unsafe {
+			al_try!(alAuxiliaryEffectSlotf(self.id, AL_EFFECTSLOT_GAIN, value as ALfloat));
+		}
+
+		Ok(())
+	}
+
+	/// Checks if the send gains are adjusted automatically based on distance.
+	pub fn is_auto_send(&self) -> bool {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = 0;
+			alGetAuxiliaryEffectSloti(self.id, AL_EFFECTSLOT_AUXILIARY_SEND_AUTO, &mut value);
+
+			value != AL_FALSE as ALint
+		}
+	}
+
+	/// Makes the send gains adjust automatically based on distance.
+	pub fn enable_auto_send(&self) {
+		let _current = self.scoped();
+
+		unsafe {
+			alAuxiliaryEffectSloti(self.id, AL_EFFECTSLOT_AUXILIARY_SEND_AUTO, AL_TRUE as ALint);
+		}
+	}
+
+	/// Makes the send gains fixed.
+	pub fn disable_auto_send(&self) {
+		let _current = self.scoped();
+
+		unsafe {
+			alAuxiliaryEffectSloti(self.id, AL_EFFECTSLOT_AUXILIARY_SEND_AUTO, AL_FALSE as ALint);
+		}
+	}
+}
+
+impl<'a> ::std::fmt::Debug for EffectSlot<'a> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::efx::EffectSlot("));
+		try!(f.write_str(&format!("{}; ", unsafe { self.id() })));
+		try!(f.write_str(&format!("gain={}", self.gain())));
+		f.write_str(")")
+	}
+}
+
+impl<'a> Drop for EffectSlot<'a> {
+	fn drop(&mut self) {
+		let _current = self.scoped();
+
+		unsafe {
+			alDeleteAuxiliaryEffectSlots(1, &self.id);
+			al_panic!();
+		}
+	}
+}
@@ -30,6 +30,9 @@ pub enum Error {
 
 	/// Unable to allocate memory.
 	OutOfMemory,
+
+	/// The named extension or feature is not supported by the implementation.
+	Unsupported(&'static str),
 }
 
 #[doc(hidden)]
@@ -100,7 +103,13 @@ impl From<ALC> for Error {
 
 impl fmt::Display for Error {
 	fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-		f.write_str(error::Error::description(self))
+		match self {
+			&Error::Unsupported(name) =>
+				f.write_str(&format!("{} is not supported.", name)),
+
+			_ =>
+				f.write_str(error::Error::description(self))
+		}
 	}
 }
 
@@ -138,6 +147,9 @@ impl error::Error for Error {
 
 			&Error::OutOfMemory =>
 				"Unable to allocate memory.",
+
+			&Error::Unsupported(..) =>
+				"The extension or feature is not supported.",
 		}
 	}
 }
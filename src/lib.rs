@@ -7,9 +7,11 @@ OpenAL wrapper for Rust.
 extern crate libc;
 extern crate openal_sys as ffi;
 
+mod sys;
+
 #[macro_use]
 mod util;
-pub use util::{Vector, Position, Direction, Velocity, Orientation, Doppler, Sample};
+pub use util::{Vector, Position, Direction, Velocity, Orientation, Doppler, DistanceModel, Sample};
 
 mod traits;
 pub use traits::{Device, Context};
@@ -27,6 +29,12 @@ pub mod source;
 pub use source::Source;
 
 mod buffer;
-pub use buffer::Buffer;
+pub use buffer::{Buffer, CallbackBuffer, Ambisonic, AmbisonicLayout, AmbisonicScaling};
+
+pub mod efx;
+
+pub mod wav;
+
+pub mod monitor;
 
 pub mod extension;
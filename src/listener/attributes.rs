@@ -1,4 +1,5 @@
 use ffi::*;
+use sys::*;
 
 /// Represents the attributes that can be set when a `Listener` is opened.
 #[derive(PartialEq, Eq, Copy, Clone, Default, Debug)]
@@ -19,6 +20,14 @@ pub struct Attributes {
 	/// A hint indicating how many `Source`s should be capable of supporting
 	/// stereo data.
 	pub stereo: Option<i32>,
+
+	/// Request HRTF to be enabled or disabled, `None` lets the implementation
+	/// decide.
+	pub hrtf: Option<bool>,
+
+	/// The index of the HRTF data set to use, as listed by
+	/// `Listener::hrtf_specifiers`.
+	pub hrtf_id: Option<i32>,
 }
 
 impl<'a> From<&'a Attributes> for Vec<ALint> {
@@ -50,6 +59,16 @@ impl<'a> From<&'a Attributes> for Vec<ALint> {
 			result.push(value);
 		}
 
+		if let Some(value) = attributes.hrtf {
+			result.push(ALC_HRTF_SOFT);
+			result.push(if value { ALC_TRUE } else { ALC_FALSE } as ALint);
+		}
+
+		if let Some(value) = attributes.hrtf_id {
+			result.push(ALC_HRTF_ID_SOFT);
+			result.push(value);
+		}
+
 		result.push(0);
 
 		result
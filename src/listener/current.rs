@@ -0,0 +1,128 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use ffi::*;
+use sys::*;
+use {Error, extension};
+
+// Whether ALC_EXT_thread_local_context is supported, it's queried once since
+// it's checked on every scoped call.
+const UNKNOWN:     u8 = 0;
+const SUPPORTED:   u8 = 1;
+const UNSUPPORTED: u8 = 2;
+
+static THREAD_LOCAL: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+/// A guard making a `Listener`'s context current, the previously current
+/// context is restored when it's dropped.
+///
+/// The guard doesn't borrow the `Listener`, so it can still be changed while
+/// the guard is alive.
+///
+/// Created by `Listener::make_current` and `Listener::make_thread_current`.
+pub struct Current {
+	previous: *mut ALCcontext,
+	thread:   bool,
+}
+
+impl Current {
+	#[doc(hidden)]
+	pub unsafe fn process(context: *mut ALCcontext) -> Result<Self, Error> {
+		let previous = alcGetCurrentContext() as *mut _;
+
+		if alcMakeContextCurrent(context) != ALC_TRUE {
+			return Err(Error::InvalidContext);
+		}
+
+		Ok(Current { previous: previous, thread: false })
+	}
+
+	#[doc(hidden)]
+	pub unsafe fn thread(context: *mut ALCcontext) -> Result<Self, Error> {
+		if !Current::is_thread_local_supported() {
+			return Err(Error::Unsupported("ALC_EXT_thread_local_context"));
+		}
+
+		let previous = alcGetThreadContext();
+
+		if alcSetThreadContext(context) != ALC_TRUE {
+			return Err(Error::InvalidContext);
+		}
+
+		Ok(Current { previous: previous, thread: true })
+	}
+
+	/// Makes the given context current for the duration of an internal
+	/// operation, nothing is done if it's already current.
+	#[doc(hidden)]
+	pub fn scope(context: *mut ALCcontext) -> Result<Option<Self>, Error> {
+		unsafe {
+			if context.is_null() || Current::get() == context {
+				Ok(None)
+			}
+			else if Current::is_thread_local_supported() {
+				Current::thread(context).map(Some)
+			}
+			else {
+				Current::process(context).map(Some)
+			}
+		}
+	}
+
+	/// Checks if ALC_EXT_thread_local_context is supported, the result is
+	/// cached after the first query.
+	#[doc(hidden)]
+	pub fn is_thread_local_supported() -> bool {
+		match THREAD_LOCAL.load(Ordering::Relaxed) {
+			SUPPORTED =>
+				true,
+
+			UNSUPPORTED =>
+				false,
+
+			_ => {
+				let supported = extension::device::is_supported("ALC_EXT_thread_local_context");
+				THREAD_LOCAL.store(if supported { SUPPORTED } else { UNSUPPORTED }, Ordering::Relaxed);
+
+				supported
+			}
+		}
+	}
+
+	/// Gets the context current on the calling thread, the thread local one
+	/// takes precedence over the process wide one.
+	#[doc(hidden)]
+	pub fn get() -> *mut ALCcontext {
+		unsafe {
+			if Current::is_thread_local_supported() {
+				let context = alcGetThreadContext();
+
+				if !context.is_null() {
+					return context;
+				}
+			}
+
+			alcGetCurrentContext() as *mut _
+		}
+	}
+}
+
+impl ::std::fmt::Debug for Current {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::listener::Current("));
+		try!(f.write_str(if self.thread { "thread" } else { "process" }));
+		f.write_str(")")
+	}
+}
+
+impl Drop for Current {
+	fn drop(&mut self) {
+		unsafe {
+			if self.thread {
+				alcSetThreadContext(self.previous);
+			}
+			else {
+				alcMakeContextCurrent(self.previous);
+			}
+		}
+	}
+}
@@ -0,0 +1,92 @@
+use std::slice;
+
+use ffi::*;
+use sys::*;
+use source::{Handle, State};
+
+/// Represents the kinds of `Event` that can be subscribed to.
+///
+/// There is no performance warning kind, the event type was dropped from the
+/// final AL_SOFT_events specification and implementations don't report it.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum EventKind {
+	/// A `Source` changed state.
+	SourceStateChanged,
+
+	/// A `Source` finished playing queued buffers.
+	BufferCompleted,
+
+	/// The device was disconnected.
+	Disconnected,
+}
+
+impl From<EventKind> for ALenum {
+	fn from(value: EventKind) -> ALenum {
+		match value {
+			EventKind::SourceStateChanged => AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT,
+			EventKind::BufferCompleted    => AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT,
+			EventKind::Disconnected       => AL_EVENT_TYPE_DISCONNECTED_SOFT,
+		}
+	}
+}
+
+/// An asynchronous event delivered to the subscriber of a `Listener`.
+///
+/// The `Handle`s can be compared against `Source`s to find the one the event
+/// refers to.
+#[derive(PartialEq, Clone, Debug)]
+pub enum Event {
+	/// The `Source` changed state.
+	SourceStateChanged {
+		/// The `Source` that changed state.
+		source: Handle,
+
+		/// The new state.
+		state: State,
+	},
+
+	/// The `Source` finished playing some of its queued buffers.
+	BufferCompleted {
+		/// The `Source` that played the buffers.
+		source: Handle,
+
+		/// The number of buffers completed.
+		count: usize,
+	},
+
+	/// The device was disconnected.
+	Disconnected {
+		/// A description of what happened.
+		message: String,
+	},
+}
+
+#[doc(hidden)]
+pub type Callback = Box<dyn FnMut(Event) + Send>;
+
+#[doc(hidden)]
+pub unsafe extern "C" fn callback(kind: ALenum, object: ALuint, param: ALuint, length: ALsizei, message: *const ALchar, user: *mut ALvoid) {
+	let callback = &mut *(user as *mut Callback);
+
+	callback(match kind {
+		AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT =>
+			Event::SourceStateChanged {
+				source: Handle::new(object),
+				state:  State::from(param as ALint),
+			},
+
+		AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT =>
+			Event::BufferCompleted {
+				source: Handle::new(object),
+				count:  param as usize,
+			},
+
+		AL_EVENT_TYPE_DISCONNECTED_SOFT =>
+			Event::Disconnected {
+				message: String::from_utf8_lossy(slice::from_raw_parts(message as *const u8, length as usize)).into_owned(),
+			},
+
+		_ =>
+			return
+	});
+}
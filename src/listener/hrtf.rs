@@ -0,0 +1,55 @@
+use ffi::*;
+use sys::*;
+
+/// Represents the HRTF status of a `Listener`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum HrtfStatus {
+	/// Unknown status, something is most likely wrong.
+	Unknown,
+
+	/// HRTF is disabled.
+	Disabled,
+
+	/// HRTF is enabled.
+	Enabled,
+
+	/// HRTF is disabled because it's not allowed on the device.
+	Denied,
+
+	/// HRTF is enabled because it must be used on the device.
+	Required,
+
+	/// HRTF is enabled because the device reported headphones.
+	HeadphonesDetected,
+
+	/// HRTF is disabled because the device does not support it with the
+	/// current format.
+	UnsupportedFormat,
+}
+
+impl From<ALCenum> for HrtfStatus {
+	fn from(value: ALCint) -> HrtfStatus {
+		match value {
+			ALC_HRTF_DISABLED_SOFT =>
+				HrtfStatus::Disabled,
+
+			ALC_HRTF_ENABLED_SOFT =>
+				HrtfStatus::Enabled,
+
+			ALC_HRTF_DENIED_SOFT =>
+				HrtfStatus::Denied,
+
+			ALC_HRTF_REQUIRED_SOFT =>
+				HrtfStatus::Required,
+
+			ALC_HRTF_HEADPHONES_DETECTED_SOFT =>
+				HrtfStatus::HeadphonesDetected,
+
+			ALC_HRTF_UNSUPPORTED_FORMAT_SOFT =>
+				HrtfStatus::UnsupportedFormat,
+
+			_ =>
+				HrtfStatus::Unknown
+		}
+	}
+}
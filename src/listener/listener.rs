@@ -3,23 +3,30 @@ use std::ptr;
 use std::mem;
 use std::str::from_utf8_unchecked;
 use std::marker::PhantomData;
+use std::time::Duration;
+use std::sync::mpsc::{channel, Receiver};
+use std::io::Read;
 
 use ffi::*;
-use {Error, Device, Context, Source, Sample, Buffer};
-use super::Attributes;
-use ::util::{Vector, Position, Velocity, Orientation, Doppler};
+use sys::*;
+use {Error, Device, Context, Source, Sample, Buffer, CallbackBuffer, Ambisonic, extension};
+use efx::{Effect, EffectSlot, Filter};
+use wav::{self, Encoding};
+use super::{Output, Current, Paused, Attributes, HrtfStatus, Event, EventKind};
+use super::event::{self, Callback};
+use ::util::{Vector, Position, Velocity, Orientation, Doppler, DistanceModel, duration};
 
 /// Represents the listener.
 ///
-/// Only one `Listener` may be open at a time.
-///
-/// _In OpenAL parlance a `Listener` contains both an OpenAL device and an
-/// OpenAL context, and that context is made current on creation. This is
-/// because there are some inconsistencies between implementations that make
-/// having multiple devices or contexts impossible._
+/// _In OpenAL parlance a `Listener` is a context on an `Output` device, a
+/// device can have several of them. The methods of a `Listener`, and of the
+/// `Source`s and other objects created from it, always act on its own context,
+/// so several `Listener`s can be used side by side without `make_current` or
+/// `make_thread_current`._
 pub struct Listener<'a> {
-	device:  *mut ALCdevice,
+	output:  Output,
 	context: *mut ALCcontext,
+	events:  Option<Box<Callback>>,
 
 	_marker: PhantomData<&'a ()>,
 }
@@ -28,82 +35,279 @@ unsafe impl<'a> Send for Listener<'a> { }
 
 impl<'a> Listener<'a> {
 	#[doc(hidden)]
-	pub unsafe fn wrap(device: *mut ALCdevice, context: *mut ALCcontext) -> Self {
-		Listener { device: device, context: context, _marker: PhantomData }
+	pub unsafe fn wrap(output: Output, context: *mut ALCcontext) -> Self {
+		Listener { output: output, context: context, events: None, _marker: PhantomData }
 	}
 }
 
 impl<'a> Listener<'a> {
 	#[doc(hidden)]
 	pub fn default(attributes: &Attributes) -> Result<Self, Error> {
+		try!(Output::default()).listener(attributes)
+	}
+
+	#[doc(hidden)]
+	pub fn open(name: &str, attributes: &Attributes) -> Result<Self, Error> {
+		try!(Output::open(name)).listener(attributes)
+	}
+
+	/// Get the `Output` device of the `Listener`, which can be used to create
+	/// more `Listener`s on the same device.
+	pub fn output(&self) -> Output {
+		self.output.clone()
+	}
+
+	/// Check if the `Listener`'s context is current on the calling thread.
+	pub fn is_current(&self) -> bool {
+		Current::get() == self.context
+	}
+
+	/// Make the `Listener`'s context current for the whole process until the
+	/// returned guard is dropped.
+	pub fn make_current(&self) -> Result<Current, Error> {
 		unsafe {
-			if !alcGetCurrentContext().is_null() {
-				return Err(Error::InvalidOperation);
-			}
+			Current::process(self.context)
+		}
+	}
 
-			let device = alcOpenDevice(ptr::null());
+	/// Make the `Listener`'s context current for the calling thread only until
+	/// the returned guard is dropped, this takes precedence over the process
+	/// wide current context.
+	pub fn make_thread_current(&self) -> Result<Current, Error> {
+		unsafe {
+			Current::thread(self.context)
+		}
+	}
 
-			if device.is_null() {
-				return Err(Error::InvalidName);
-			}
+	fn device(&self) -> *mut ALCdevice {
+		Device::as_ptr(&self.output) as *mut _
+	}
 
-			let context = alcCreateContext(device, Vec::from(attributes).as_ptr());
+	// Make the context current for the duration of an internal operation.
+	fn scoped(&self) -> Result<Option<Current>, Error> {
+		Current::scope(self.context)
+	}
 
-			if context.is_null() {
-				al_try!(&device, ());
-			}
+	/// Process the `Listener`. See OpenAL documentation for `alcProcessContext`.
+	pub fn process(&mut self) {
+		unsafe {
+			alcProcessContext(self.context);
+		}
+	}
 
-			if alcMakeContextCurrent(context) != ALC_TRUE {
-				al_try!(&device, ());
+	/// Suspend the `Listener`. See OpenAL documentation for `alcSuspendContext`.
+	pub fn suspend(&mut self) {
+		unsafe {
+			alcSuspendContext(self.context);
+		}
+	}
+
+	/// Reset the `Listener` with the given attributes, keeping all `Source`s
+	/// and `Buffer`s valid.
+	pub fn reset(&mut self, attributes: &Attributes) -> Result<(), Error> {
+		if !extension::device::is_supported_by(self, "ALC_SOFT_HRTF") {
+			return Err(Error::Unsupported("ALC_SOFT_HRTF"));
+		}
+
+		unsafe {
+			if alcResetDeviceSOFT(self.device(), Vec::from(attributes).as_ptr()) != ALC_TRUE {
+				al_try!(self, ());
 			}
+		}
 
-			Ok(Listener::wrap(device, context))
+		Ok(())
+	}
+
+	/// Check if the device is still connected, a disconnected device stays
+	/// silent until it's reopened.
+	pub fn is_connected(&self) -> Result<bool, Error> {
+		if !extension::device::is_supported_by(self, "ALC_EXT_disconnect") {
+			return Err(Error::Unsupported("ALC_EXT_disconnect"));
+		}
+
+		unsafe {
+			let mut value = 0;
+			al_try!(self, alcGetIntegerv(self.device(), ALC_CONNECTED, 1, &mut value));
+
+			Ok(value == ALC_TRUE as ALCint)
 		}
 	}
 
-	#[doc(hidden)]
-	pub fn open(name: &str, attributes: &Attributes) -> Result<Self, Error> {
+	/// Move the device to the named output, or to the default one, keeping all
+	/// `Listener`s on the device and their `Source`s and `Buffer`s valid.
+	pub fn reopen(&mut self, name: Option<&str>, attributes: &Attributes) -> Result<(), Error> {
+		if !extension::device::is_supported_by(self, "ALC_SOFT_reopen_device") {
+			return Err(Error::Unsupported("ALC_SOFT_reopen_device"));
+		}
+
+		let name = name.map(|name| CString::new(name.as_bytes()).unwrap());
+
 		unsafe {
-			if !alcGetCurrentContext().is_null() {
-				return Err(Error::InvalidOperation);
+			if alcReopenDeviceSOFT(self.device(), name.as_ref().map_or(ptr::null(), |name| name.as_ptr()), Vec::from(attributes).as_ptr()) != ALC_TRUE {
+				al_try!(self, ());
+				return Err(Error::InvalidDevice);
 			}
+		}
+
+		Ok(())
+	}
 
-			let device = alcOpenDevice(CString::new(name.as_bytes()).unwrap().as_ptr());
+	/// Pause the device, stopping the mixer for every `Listener` on it until
+	/// the returned guard is dropped.
+	pub fn pause_device(&self) -> Result<Paused, Error> {
+		if !extension::device::is_supported_by(self, "ALC_SOFT_pause_device") {
+			return Err(Error::Unsupported("ALC_SOFT_pause_device"));
+		}
 
-			if device.is_null() {
-				return Err(Error::InvalidName);
-			}
+		unsafe {
+			al_try!(self, alcDevicePauseSOFT(self.device()));
 
-			let context = alcCreateContext(device, Vec::from(attributes).as_ptr());
+			Ok(Paused::new(self.output()))
+		}
+	}
 
-			if context.is_null() {
-				al_try!(&device, ());
-			}
+	/// Resume the device, this is only needed when the guard returned by
+	/// `pause_device` has been forgotten.
+	pub fn resume_device(&self) -> Result<(), Error> {
+		if !extension::device::is_supported_by(self, "ALC_SOFT_pause_device") {
+			return Err(Error::Unsupported("ALC_SOFT_pause_device"));
+		}
+
+		unsafe {
+			al_try!(self, alcDeviceResumeSOFT(self.device()));
+		}
+
+		Ok(())
+	}
+
+	/// Get the HRTF status.
+	pub fn hrtf_status(&self) -> Result<HrtfStatus, Error> {
+		if !extension::device::is_supported_by(self, "ALC_SOFT_HRTF") {
+			return Err(Error::Unsupported("ALC_SOFT_HRTF"));
+		}
+
+		unsafe {
+			let mut value = 0;
+			al_try!(self, alcGetIntegerv(self.device(), ALC_HRTF_STATUS_SOFT, 1, &mut value));
 
-			if alcMakeContextCurrent(context) != ALC_TRUE {
-				al_try!(&device, ());
+			Ok(HrtfStatus::from(value))
+		}
+	}
+
+	/// Get the names of the available HRTF data sets, the position in the list
+	/// is the index to use for `Attributes::hrtf_id`.
+	pub fn hrtf_specifiers(&self) -> Result<Vec<String>, Error> {
+		if !extension::device::is_supported_by(self, "ALC_SOFT_HRTF") {
+			return Err(Error::Unsupported("ALC_SOFT_HRTF"));
+		}
+
+		unsafe {
+			let mut count = 0;
+			al_try!(self, alcGetIntegerv(self.device(), ALC_NUM_HRTF_SPECIFIERS_SOFT, 1, &mut count));
+
+			let mut result = Vec::with_capacity(count as usize);
+
+			for index in 0 .. count {
+				let name = al_try!(self, alcGetStringiSOFT(self.device(), ALC_HRTF_SPECIFIER_SOFT, index));
+				result.push(CStr::from_ptr(name).to_string_lossy().into_owned());
 			}
 
-			Ok(Listener::wrap(device, context))
+			Ok(result)
 		}
 	}
 
-	/// Process the `Listener`. See OpenAL documentation for `alcProcessContext`.
-	pub fn process(&mut self) {
+	/// Get the device clock, the time spent rendering since the device was
+	/// opened.
+	pub fn clock(&self) -> Result<Duration, Error> {
+		if !extension::device::is_supported_by(self, "ALC_SOFT_device_clock") {
+			return Err(Error::Unsupported("ALC_SOFT_device_clock"));
+		}
+
 		unsafe {
-			alcProcessContext(self.context);
+			let mut value = 0;
+			al_try!(self, alcGetInteger64vSOFT(self.device(), ALC_DEVICE_CLOCK_SOFT, 1, &mut value));
+
+			Ok(duration(value))
 		}
 	}
 
-	/// Suspend the `Listener`. See OpenAL documentation for `alcSuspendContext`.
-	pub fn suspend(&mut self) {
+	/// Get the output latency of the device.
+	pub fn latency(&self) -> Result<Duration, Error> {
+		if !extension::device::is_supported_by(self, "ALC_SOFT_device_clock") {
+			return Err(Error::Unsupported("ALC_SOFT_device_clock"));
+		}
+
 		unsafe {
-			alcSuspendContext(self.context);
+			let mut value = 0;
+			al_try!(self, alcGetInteger64vSOFT(self.device(), ALC_DEVICE_LATENCY_SOFT, 1, &mut value));
+
+			Ok(duration(value))
 		}
 	}
 
+	/// Subscribe to the given kinds of `Event`, the callback is called from an
+	/// internal thread.
+	///
+	/// Any previous subscription is replaced.
+	pub fn subscribe<F>(&mut self, kinds: &[EventKind], callback: F) -> Result<(), Error>
+		where F: FnMut(Event) + Send + 'static
+	{
+		self.unsubscribe();
+
+		let _current = try!(self.scoped());
+
+		if !extension::is_supported("AL_SOFT_events") {
+			return Err(Error::Unsupported("AL_SOFT_events"));
+		}
+
+		unsafe {
+			let mut callback: Box<Callback> = Box::new(Box::new(callback));
+			alEventCallbackSOFT(Some(event::callback), &mut *callback as *mut Callback as *mut _);
+			self.events = Some(callback);
+
+			let kinds = kinds.iter().map(|&k| k.into()).collect::<Vec<ALenum>>();
+			al_try!(alEventControlSOFT(kinds.len() as ALsizei, kinds.as_ptr(), AL_TRUE));
+		}
+
+		Ok(())
+	}
+
+	/// Subscribe to the given kinds of `Event`, delivering them through a
+	/// channel.
+	///
+	/// Any previous subscription is replaced.
+	pub fn events(&mut self, kinds: &[EventKind]) -> Result<Receiver<Event>, Error> {
+		let (sender, receiver) = channel();
+
+		try!(self.subscribe(kinds, move |event| {
+			sender.send(event).ok();
+		}));
+
+		Ok(receiver)
+	}
+
+	/// Stop receiving `Event`s.
+	pub fn unsubscribe(&mut self) {
+		if self.events.is_none() {
+			return;
+		}
+
+		let _current = self.scoped();
+
+		unsafe {
+			let kinds = [EventKind::SourceStateChanged.into(), EventKind::BufferCompleted.into(), EventKind::Disconnected.into()];
+
+			alEventControlSOFT(kinds.len() as ALsizei, kinds.as_ptr(), AL_FALSE);
+			alEventCallbackSOFT(None, ptr::null_mut());
+		}
+
+		self.events = None;
+	}
+
 	/// Create a new `Source`.
 	pub fn source<'b>(&self) -> Result<Source<'b>, Error> where 'a: 'b {
+		let _current = try!(self.scoped());
+
 		unsafe {
 			Source::new()
 		}
@@ -111,13 +315,122 @@ impl<'a> Listener<'a> {
 
 	/// Create a new `Buffer` and fill it.
 	pub fn buffer<'b, T: Sample>(&self, channels: u16, data: &[T], rate: u32) -> Result<Buffer<'b>, Error> where 'a: 'b {
+		let _current = try!(self.scoped());
+
 		unsafe {
 			Buffer::new(channels, data, rate)
 		}
 	}
 
+	/// Create a new `Buffer` from a RIFF/WAVE stream, using the most precise
+	/// sample type the implementation supports for the file's format.
+	pub fn buffer_from_wav<'b, R: Read>(&self, reader: R) -> Result<Buffer<'b>, wav::Error> where 'a: 'b {
+		let _current = try!(self.scoped());
+
+		let mut reader = try!(wav::Reader::new(reader));
+		let format     = reader.format();
+		let channels   = format.channels;
+
+		let float  = <f32 as Sample>::format(channels).is_ok();
+		let double = <f64 as Sample>::format(channels).is_ok();
+
+		unsafe {
+			Ok(try!(match (format.encoding, format.bits) {
+				(Encoding::Integer, 8) =>
+					Buffer::new(channels, &try!(reader.samples::<u8>()), format.rate),
+
+				(Encoding::Integer, 16) =>
+					Buffer::new(channels, &try!(reader.samples::<i16>()), format.rate),
+
+				(Encoding::Float, 64) if double =>
+					Buffer::new(channels, &try!(reader.samples::<f64>()), format.rate),
+
+				_ if float =>
+					Buffer::new(channels, &try!(reader.samples::<f32>()), format.rate),
+
+				_ =>
+					Buffer::new(channels, &try!(reader.samples::<i16>()), format.rate),
+			}))
+		}
+	}
+
+	/// Create a new `Buffer` and fill it with ambisonic B-Format data.
+	pub fn ambisonic_buffer<'b, T: Sample>(&self, ambisonic: &Ambisonic, data: &[T], rate: u32) -> Result<Buffer<'b>, Error> where 'a: 'b {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			Buffer::ambisonic(ambisonic, data, rate)
+		}
+	}
+
+	/// Create a new `CallbackBuffer` pulling its data from the given callback.
+	pub fn callback_buffer<'b, T, F>(&self, channels: u16, rate: u32, callback: F) -> Result<CallbackBuffer<'b, T>, Error>
+		where 'a: 'b, T: Sample, F: FnMut(&mut [T]) -> usize + Send + 'static
+	{
+		let _current = try!(self.scoped());
+
+		unsafe {
+			CallbackBuffer::new(channels, rate, callback)
+		}
+	}
+
+	/// Create a new `Effect`.
+	pub fn effect<'b>(&self) -> Result<Effect<'b>, Error> where 'a: 'b {
+		let _current = try!(self.scoped());
+
+		if !extension::device::is_supported_by(self, "ALC_EXT_EFX") {
+			return Err(Error::Unsupported("ALC_EXT_EFX"));
+		}
+
+		unsafe {
+			Effect::new()
+		}
+	}
+
+	/// Create a new `EffectSlot`.
+	pub fn effect_slot<'b>(&self) -> Result<EffectSlot<'b>, Error> where 'a: 'b {
+		let _current = try!(self.scoped());
+
+		if !extension::device::is_supported_by(self, "ALC_EXT_EFX") {
+			return Err(Error::Unsupported("ALC_EXT_EFX"));
+		}
+
+		unsafe {
+			EffectSlot::new()
+		}
+	}
+
+	/// Create a new `Filter`.
+	pub fn filter<'b>(&self) -> Result<Filter<'b>, Error> where 'a: 'b {
+		let _current = try!(self.scoped());
+
+		if !extension::device::is_supported_by(self, "ALC_EXT_EFX") {
+			return Err(Error::Unsupported("ALC_EXT_EFX"));
+		}
+
+		unsafe {
+			Filter::new()
+		}
+	}
+
+	/// Get the maximum number of auxiliary sends per `Source`.
+	pub fn max_auxiliary_sends(&self) -> usize {
+		if !extension::device::is_supported_by(self, "ALC_EXT_EFX") {
+			return 0;
+		}
+
+		unsafe {
+			let mut value = 0;
+			alcGetIntegerv(self.device(), ALC_MAX_AUXILIARY_SENDS, 1, &mut value);
+
+			value as usize
+		}
+	}
+
 	/// Get the vendor name.
 	pub fn vendor(&self) -> &'static str {
+		let _current = self.scoped();
+
 		unsafe {
 			from_utf8_unchecked(CStr::from_ptr(alGetString(AL_VENDOR)).to_bytes())
 		}
@@ -125,6 +438,8 @@ impl<'a> Listener<'a> {
 
 	/// Get the OpenAL specification version and the context specific version.
 	pub fn version(&self) -> (&'static str, &'static str) {
+		let _current = self.scoped();
+
 		unsafe {
 			let     string = from_utf8_unchecked(CStr::from_ptr(alGetString(AL_VERSION)).to_bytes());
 			let mut pieces = string.splitn(2, ' ');
@@ -135,6 +450,8 @@ impl<'a> Listener<'a> {
 
 	/// Get the name of the renderer.
 	pub fn renderer(&self) -> &'static str {
+		let _current = self.scoped();
+
 		unsafe {
 			from_utf8_unchecked(CStr::from_ptr(alGetString(AL_RENDERER)).to_bytes())
 		}
@@ -142,6 +459,8 @@ impl<'a> Listener<'a> {
 
 	/// Get a list of extensions supported.
 	pub fn extensions(&self) -> Vec<&'static str> {
+		let _current = self.scoped();
+
 		unsafe {
 			from_utf8_unchecked(CStr::from_ptr(alGetString(AL_EXTENSIONS)).to_bytes())
 				.split(' ')
@@ -151,6 +470,8 @@ impl<'a> Listener<'a> {
 
 	/// Get the doppler factor and velocity.
 	pub fn doppler(&self) -> Doppler {
+		let _current = self.scoped();
+
 		unsafe {
 			Doppler {
 				factor:   alGetFloat(AL_DOPPLER_FACTOR),
@@ -161,14 +482,75 @@ impl<'a> Listener<'a> {
 
 	/// Set the doppler factor and velocity.
 	pub fn set_doppler(&mut self, value: Doppler) {
+		let _current = self.scoped();
+
 		unsafe {
 			alDopplerFactor(value.factor);
 			alDopplerVelocity(value.velocity);
 		}
 	}
 
+	/// Get the distance model.
+	pub fn distance_model(&self) -> DistanceModel {
+		let _current = self.scoped();
+
+		unsafe {
+			DistanceModel::from(alGetInteger(AL_DISTANCE_MODEL))
+		}
+	}
+
+	/// Set the distance model.
+	pub fn set_distance_model(&mut self, value: DistanceModel) {
+		let _current = self.scoped();
+
+		unsafe {
+			alDistanceModel(value.into());
+		}
+	}
+
+	/// Check if `Source`s can override the distance model.
+	pub fn is_source_distance_model(&self) -> bool {
+		let _current = self.scoped();
+
+		if !extension::is_supported("AL_EXT_source_distance_model") {
+			return false;
+		}
+
+		unsafe {
+			alIsEnabled(AL_SOURCE_DISTANCE_MODEL) == AL_TRUE
+		}
+	}
+
+	/// Let `Source`s override the distance model.
+	pub fn enable_source_distance_model(&mut self) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		if !extension::is_supported("AL_EXT_source_distance_model") {
+			return Err(Error::Unsupported("AL_EXT_source_distance_model"));
+		}
+
+		unsafe {
+			al_try!(alEnable(AL_SOURCE_DISTANCE_MODEL));
+		}
+
+		Ok(())
+	}
+
+	/// Make all `Source`s use the distance model of the `Listener`.
+	pub fn disable_source_distance_model(&mut self) {
+		let _current = self.scoped();
+
+		unsafe {
+			if extension::is_supported("AL_EXT_source_distance_model") {
+				alDisable(AL_SOURCE_DISTANCE_MODEL);
+			}
+		}
+	}
+
 	/// Get the speed of sound.
 	pub fn speed_of_sound(&self) -> f32 {
+		let _current = self.scoped();
+
 		unsafe {
 			alGetFloat(AL_SPEED_OF_SOUND)
 		}
@@ -176,6 +558,8 @@ impl<'a> Listener<'a> {
 
 	/// Set the speed of sound.
 	pub fn set_speed_of_sound(&mut self, value: f32) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSpeedOfSound(value as ALfloat);
 		}
@@ -183,6 +567,8 @@ impl<'a> Listener<'a> {
 
 	/// Get the listener gain.
 	pub fn gain(&self) -> f32 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0.0;
 			alGetListenerf(AL_GAIN, &mut value);
@@ -193,6 +579,8 @@ impl<'a> Listener<'a> {
 
 	/// Set the listener gain.
 	pub fn set_gain(&mut self, value: f32) {
+		let _current = self.scoped();
+
 		unsafe {
 			alListenerf(AL_GAIN, value as ALfloat);
 		}
@@ -200,6 +588,8 @@ impl<'a> Listener<'a> {
 
 	/// Get the listener position.
 	pub fn position(&self) -> Position {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = Position(Vector { x: 0.0, y: 0.0, z: 0.0 });
 			alGetListenerfv(AL_POSITION, mem::transmute(&mut value));
@@ -210,6 +600,8 @@ impl<'a> Listener<'a> {
 
 	/// Set the listener position.
 	pub fn set_position(&mut self, value: &Position) {
+		let _current = self.scoped();
+
 		unsafe {
 			alListenerfv(AL_POSITION, mem::transmute(value));
 		}
@@ -217,6 +609,8 @@ impl<'a> Listener<'a> {
 
 	/// Get the listener velocity.
 	pub fn velocity(&self) -> Velocity {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = Velocity(Vector { x: 0.0, y: 0.0, z: 0.0 });
 			alGetListenerfv(AL_VELOCITY, mem::transmute(&mut value));
@@ -227,6 +621,8 @@ impl<'a> Listener<'a> {
 
 	/// Set the listener velocity.
 	pub fn set_velocity(&mut self, value: &Velocity) {
+		let _current = self.scoped();
+
 		unsafe {
 			alListenerfv(AL_VELOCITY, mem::transmute(value));
 		}
@@ -234,6 +630,8 @@ impl<'a> Listener<'a> {
 
 	/// Get the listener orientation.
 	pub fn orientation(&self) -> Orientation {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = Orientation(Vector { x: 0.0, y: 0.0, z: 0.0 }, Vector { x: 0.0, y: 0.0, z: 0.0 });
 			alGetListenerfv(AL_ORIENTATION, mem::transmute(&mut value));
@@ -244,6 +642,8 @@ impl<'a> Listener<'a> {
 
 	/// Set the listener orientation.
 	pub fn set_orientation(&mut self, value: &Orientation) {
+		let _current = self.scoped();
+
 		unsafe {
 			alListenerfv(AL_ORIENTATION, mem::transmute(value));
 		}
@@ -252,7 +652,7 @@ impl<'a> Listener<'a> {
 
 unsafe impl<'a> Device for Listener<'a> {
 	fn as_ptr(&self) -> *const ALCdevice {
-		self.device as *const _
+		Device::as_ptr(&self.output)
 	}
 }
 
@@ -272,6 +672,7 @@ impl<'a> ::std::fmt::Debug for Listener<'a> {
 		try!(f.write_str(&format!("extensions={:?}; ", self.extensions())));
 
 		try!(f.write_str(&format!("doppler={:?} ", self.doppler())));
+		try!(f.write_str(&format!("distance_model={:?} ", self.distance_model())));
 		try!(f.write_str(&format!("speed_of_sound={} ", self.speed_of_sound())));
 		try!(f.write_str(&format!("gain={} ", self.gain())));
 		try!(f.write_str(&format!("position={:?} ", self.position())));
@@ -284,17 +685,19 @@ impl<'a> ::std::fmt::Debug for Listener<'a> {
 
 impl<'a> Drop for Listener<'a> {
 	fn drop(&mut self) {
+		self.unsubscribe();
+
 		unsafe {
-			if alcMakeContextCurrent(ptr::null_mut()) != ALC_TRUE {
+			if Current::is_thread_local_supported() && alcGetThreadContext() == self.context {
+				alcSetThreadContext(ptr::null_mut());
+			}
+
+			if alcGetCurrentContext() as *mut _ == self.context && alcMakeContextCurrent(ptr::null_mut()) != ALC_TRUE {
 				al_panic!(self);
 			}
 
 			alcDestroyContext(self.context);
 			al_panic!(self);
-
-			if alcCloseDevice(self.device) != ALC_TRUE {
-				al_panic!(self);
-			}
 		}
 	}
 }
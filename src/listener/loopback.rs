@@ -0,0 +1,135 @@
+use std::ptr;
+use std::ops::{Deref, DerefMut};
+use std::marker::PhantomData;
+
+use ffi::*;
+use sys::*;
+use {Error, Device, Context, Sample, extension};
+use super::{Listener, Output, Attributes};
+
+/// Represents a loopback listener.
+///
+/// A `Loopback` renders the mix into memory instead of an output device, the
+/// samples are produced on demand through `render_samples`. Everything that
+/// works with a `Listener` works with a `Loopback` too.
+pub struct Loopback<'a, T: Sample> {
+	listener: Listener<'a>,
+	channels: u16,
+
+	_marker: PhantomData<T>,
+}
+
+impl<'a, T: Sample> Loopback<'a, T> {
+	#[doc(hidden)]
+	pub fn open(channels: u16, rate: u32, attributes: &Attributes) -> Result<Self, Error> {
+		unsafe {
+			if !extension::device::is_supported("ALC_SOFT_loopback") {
+				return Err(Error::Unsupported("ALC_SOFT_loopback"));
+			}
+
+			let layout = try!(layout(channels));
+			let kind   = try!(<T as Sample>::render_type());
+			let device = alcLoopbackOpenDeviceSOFT(ptr::null());
+
+			if device.is_null() {
+				return Err(Error::InvalidDevice);
+			}
+
+			if alcIsRenderFormatSupportedSOFT(device, rate as ALCsizei, layout, kind) != ALC_TRUE {
+				alcCloseDevice(device);
+				return Err(Error::InvalidValue);
+			}
+
+			let mut attributes = Vec::from(&Attributes { frequency: Some(rate as i32), .. *attributes });
+			attributes.pop();
+			attributes.extend(&[ALC_FORMAT_CHANNELS_SOFT, layout, ALC_FORMAT_TYPE_SOFT, kind, 0]);
+
+			let output  = Output::wrap(device);
+			let context = alcCreateContext(device, attributes.as_ptr());
+
+			if context.is_null() {
+				al_try!(&output, ());
+			}
+
+			if alcGetCurrentContext().is_null() && alcMakeContextCurrent(context) != ALC_TRUE {
+				alcDestroyContext(context);
+				al_try!(&output, ());
+			}
+
+			Ok(Loopback {
+				listener: Listener::wrap(output, context),
+				channels: channels,
+
+				_marker: PhantomData,
+			})
+		}
+	}
+
+	/// The number of channels rendered for each sample frame.
+	pub fn channels(&self) -> u16 {
+		self.channels
+	}
+
+	/// Renders the mix into the given slice, the length of the slice must be
+	/// a multiple of the number of channels.
+	pub fn render_samples(&mut self, data: &mut [T]) -> Result<(), Error> {
+		if data.len() % self.channels as usize != 0 {
+			return Err(Error::InvalidValue);
+		}
+
+		unsafe {
+			al_try!(self,
+				alcRenderSamplesSOFT(Device::as_mut_ptr(self), data.as_mut_ptr() as *mut _,
+					(data.len() / self.channels as usize) as ALCsizei));
+		}
+
+		Ok(())
+	}
+}
+
+fn layout(channels: u16) -> Result<ALCenum, Error> {
+	match channels {
+		1 => Ok(ALC_MONO_SOFT),
+		2 => Ok(ALC_STEREO_SOFT),
+		4 => Ok(ALC_QUAD_SOFT),
+		6 => Ok(ALC_5POINT1_SOFT),
+		7 => Ok(ALC_6POINT1_SOFT),
+		8 => Ok(ALC_7POINT1_SOFT),
+		_ => Err(Error::InvalidValue),
+	}
+}
+
+impl<'a, T: Sample> Deref for Loopback<'a, T> {
+	type Target = Listener<'a>;
+
+	fn deref(&self) -> &<Self as Deref>::Target {
+		&self.listener
+	}
+}
+
+impl<'a, T: Sample> DerefMut for Loopback<'a, T> {
+	fn deref_mut(&mut self) -> &mut<Self as Deref>::Target {
+		&mut self.listener
+	}
+}
+
+unsafe impl<'a, T: Sample> Device for Loopback<'a, T> {
+	fn as_ptr(&self) -> *const ALCdevice {
+		Device::as_ptr(&self.listener)
+	}
+}
+
+unsafe impl<'a, T: Sample> Context for Loopback<'a, T> {
+	fn as_ptr(&self) -> *const ALCcontext {
+		Context::as_ptr(&self.listener)
+	}
+}
+
+impl<'a, T: Sample> ::std::fmt::Debug for Loopback<'a, T> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::listener::Loopback("));
+		try!(f.write_str(&format!("channels={}; ", self.channels)));
+		try!(f.write_str(&format!("{:?}", self.listener)));
+		f.write_str(")")
+	}
+}
@@ -5,13 +5,32 @@ Helpers related to `Listener`.
 mod listener;
 pub use self::listener::Listener;
 
+mod output;
+pub use self::output::Output;
+
+mod current;
+pub use self::current::Current;
+
+mod paused;
+pub use self::paused::Paused;
+
 mod attributes;
 pub use self::attributes::Attributes;
 
+mod event;
+pub use self::event::{Event, EventKind};
+
+mod hrtf;
+pub use self::hrtf::HrtfStatus;
+
+mod loopback;
+pub use self::loopback::Loopback;
+
 use std::ptr;
 
 use ffi::*;
-use {Error, extension};
+use {Error, Sample, extension};
+use util::{enumeration, string, strings};
 
 /// Opens the default output device.
 pub fn default<'a>(attributes: &Attributes) -> Result<Listener<'a>, Error> {
@@ -23,25 +42,62 @@ pub fn open<'a>(name: &str, attributes: &Attributes) -> Result<Listener<'a>, Err
 	Listener::open(name, attributes)
 }
 
-/// Gets a list of available output device names.
-pub fn devices() -> Vec<&'static str> {
-	use std::ffi::CStr;
-	use std::str::from_utf8_unchecked;
-	use libc::strlen;
+/// Opens the default output device without creating a `Listener`.
+pub fn default_output() -> Result<Output, Error> {
+	Output::default()
+}
+
+/// Opens the named output device without creating a `Listener`.
+pub fn open_output(name: &str) -> Result<Output, Error> {
+	Output::open(name)
+}
 
-	let mut result = Vec::new();
+/// Opens a loopback device rendering with the given number of channels and
+/// sample rate.
+pub fn loopback<'a, T: Sample>(channels: u16, rate: u32, attributes: &Attributes) -> Result<Loopback<'a, T>, Error> {
+	Loopback::open(channels, rate, attributes)
+}
+
+/// Gets a list of available output device names.
+pub fn devices() -> Vec<String> {
+	let _lock = enumeration();
 
 	unsafe {
 		if extension::device::is_supported("ALC_ENUMERATION_EXT") {
-			let mut ptr = alcGetString(ptr::null(), ALC_DEVICE_SPECIFIER);
+			strings(alcGetString(ptr::null_mut(), ALC_DEVICE_SPECIFIER))
+		}
+		else {
+			Vec::new()
+		}
+	}
+}
 
-			while *ptr != 0 {
-				result.push(from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes()));
+/// Gets a list of every available output device name, where `devices` may
+/// collapse several outputs of a driver under one name.
+///
+/// Falls back to `devices` if ALC_ENUMERATE_ALL_EXT is not supported.
+pub fn all_devices() -> Vec<String> {
+	if !extension::device::is_supported("ALC_ENUMERATE_ALL_EXT") {
+		return devices();
+	}
 
-				ptr = ptr.offset(strlen(ptr) as isize + 1);
-			}
-		}
+	let _lock = enumeration();
+
+	unsafe {
+		strings(alcGetString(ptr::null_mut(), ALC_ALL_DEVICES_SPECIFIER))
 	}
+}
+
+/// Gets the name of the default output device.
+pub fn default_device() -> Option<String> {
+	let _lock = enumeration();
 
-	result
+	unsafe {
+		if extension::device::is_supported("ALC_ENUMERATE_ALL_EXT") {
+			string(alcGetString(ptr::null_mut(), ALC_DEFAULT_ALL_DEVICES_SPECIFIER))
+		}
+		else {
+			string(alcGetString(ptr::null_mut(), ALC_DEFAULT_DEVICE_SPECIFIER))
+		}
+	}
 }
@@ -0,0 +1,99 @@
+use std::ffi::CString;
+use std::ptr;
+use std::sync::Arc;
+
+use ffi::*;
+use {Error, Device};
+use super::{Listener, Attributes};
+
+/// Represents an output device.
+///
+/// An `Output` can have several `Listener`s, each with its own context, the
+/// device is closed once the `Output` and all its `Listener`s are dropped.
+#[derive(Clone)]
+pub struct Output {
+	handle: Arc<Handle>,
+}
+
+struct Handle(*mut ALCdevice);
+
+unsafe impl Send for Handle { }
+unsafe impl Sync for Handle { }
+
+impl Output {
+	#[doc(hidden)]
+	pub unsafe fn wrap(ptr: *mut ALCdevice) -> Self {
+		Output { handle: Arc::new(Handle(ptr)) }
+	}
+}
+
+impl Output {
+	#[doc(hidden)]
+	pub fn default() -> Result<Self, Error> {
+		unsafe {
+			let device = alcOpenDevice(ptr::null());
+
+			if device.is_null() {
+				return Err(Error::InvalidName);
+			}
+
+			Ok(Output::wrap(device))
+		}
+	}
+
+	#[doc(hidden)]
+	pub fn open(name: &str) -> Result<Self, Error> {
+		unsafe {
+			let device = alcOpenDevice(CString::new(name.as_bytes()).unwrap().as_ptr());
+
+			if device.is_null() {
+				return Err(Error::InvalidName);
+			}
+
+			Ok(Output::wrap(device))
+		}
+	}
+
+	/// Creates a new `Listener` on the device, its context is made current
+	/// only if no other context is.
+	pub fn listener<'a>(&self, attributes: &Attributes) -> Result<Listener<'a>, Error> {
+		unsafe {
+			let context = alcCreateContext(self.handle.0, Vec::from(attributes).as_ptr());
+
+			if context.is_null() {
+				al_try!(self, ());
+			}
+
+			if alcGetCurrentContext().is_null() && alcMakeContextCurrent(context) != ALC_TRUE {
+				alcDestroyContext(context);
+				al_try!(self, ());
+			}
+
+			Ok(Listener::wrap(self.clone(), context))
+		}
+	}
+}
+
+unsafe impl Device for Output {
+	fn as_ptr(&self) -> *const ALCdevice {
+		self.handle.0 as *const _
+	}
+}
+
+impl ::std::fmt::Debug for Output {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::listener::Output("));
+		try!(f.write_str(&format!("{:?}", self.handle.0)));
+		f.write_str(")")
+	}
+}
+
+impl Drop for Handle {
+	fn drop(&mut self) {
+		unsafe {
+			if alcCloseDevice(self.0) != ALC_TRUE {
+				al_panic!(&self.0);
+			}
+		}
+	}
+}
@@ -0,0 +1,40 @@
+use sys::*;
+use Device;
+use super::Output;
+
+/// A guard keeping an `Output` device paused, the device is resumed when it's
+/// dropped.
+///
+/// Created by `Listener::pause_device`.
+pub struct Paused {
+	output: Output,
+}
+
+impl Paused {
+	#[doc(hidden)]
+	pub unsafe fn new(output: Output) -> Self {
+		Paused { output: output }
+	}
+}
+
+impl Paused {
+	/// Resumes the device.
+	pub fn resume(self) { }
+}
+
+impl ::std::fmt::Debug for Paused {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::listener::Paused("));
+		try!(f.write_str(&format!("{:?}", self.output)));
+		f.write_str(")")
+	}
+}
+
+impl Drop for Paused {
+	fn drop(&mut self) {
+		unsafe {
+			alcDeviceResumeSOFT(Device::as_mut_ptr(&mut self.output));
+			al_panic!(&self.output);
+		}
+	}
+}
@@ -0,0 +1,104 @@
+use std::slice;
+
+use ffi::*;
+use sys::*;
+
+/// Represents the kinds of `DeviceEvent`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum DeviceEventKind {
+	/// A device was added.
+	Added,
+
+	/// A device was removed.
+	Removed,
+
+	/// The default device changed.
+	DefaultChanged,
+}
+
+impl From<DeviceEventKind> for ALCenum {
+	fn from(value: DeviceEventKind) -> ALCenum {
+		match value {
+			DeviceEventKind::Added          => ALC_EVENT_TYPE_DEVICE_ADDED_SOFT,
+			DeviceEventKind::Removed        => ALC_EVENT_TYPE_DEVICE_REMOVED_SOFT,
+			DeviceEventKind::DefaultChanged => ALC_EVENT_TYPE_DEFAULT_DEVICE_CHANGED_SOFT,
+		}
+	}
+}
+
+/// Represents the type of device a `DeviceEvent` refers to.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum DeviceType {
+	/// An output device.
+	Playback,
+
+	/// A capture device.
+	Capture,
+}
+
+impl From<DeviceType> for ALCenum {
+	fn from(value: DeviceType) -> ALCenum {
+		match value {
+			DeviceType::Playback => ALC_PLAYBACK_DEVICE_SOFT,
+			DeviceType::Capture  => ALC_CAPTURE_DEVICE_SOFT,
+		}
+	}
+}
+
+/// A change in the system audio devices.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct DeviceEvent {
+	/// What happened.
+	pub kind: DeviceEventKind,
+
+	/// The type of the device.
+	pub device_type: DeviceType,
+
+	/// The specifier of the device, it can be used to open it.
+	///
+	/// It's empty when an event reported by the implementation can't be
+	/// matched to a change in the devices.
+	pub name: String,
+
+	/// The description provided by the implementation when it reports the
+	/// event itself, `None` when the devices are polled.
+	pub message: Option<String>,
+}
+
+#[doc(hidden)]
+pub type Callback = Box<dyn FnMut(DeviceEvent) + Send>;
+
+#[doc(hidden)]
+pub unsafe extern "C" fn callback(kind: ALCenum, device_type: ALCenum, _device: *mut ALCdevice, length: ALCsizei, message: *const ALCchar, user: *mut ALCvoid) {
+	let callback = &mut *(user as *mut Callback);
+
+	let kind = match kind {
+		ALC_EVENT_TYPE_DEVICE_ADDED_SOFT           => DeviceEventKind::Added,
+		ALC_EVENT_TYPE_DEVICE_REMOVED_SOFT         => DeviceEventKind::Removed,
+		ALC_EVENT_TYPE_DEFAULT_DEVICE_CHANGED_SOFT => DeviceEventKind::DefaultChanged,
+
+		_ => return
+	};
+
+	let device_type = match device_type {
+		ALC_PLAYBACK_DEVICE_SOFT => DeviceType::Playback,
+		ALC_CAPTURE_DEVICE_SOFT  => DeviceType::Capture,
+
+		_ => return
+	};
+
+	let message = if message.is_null() {
+		None
+	}
+	else {
+		Some(String::from_utf8_lossy(slice::from_raw_parts(message as *const u8, length as usize)).into_owned())
+	};
+
+	// The name is resolved by the `Monitor` from the current devices.
+	callback(DeviceEvent {
+		kind:        kind,
+		device_type: device_type,
+		name:        String::new(),
+		message:     message,
+	});
+}
@@ -0,0 +1,43 @@
+/*!
+Notifications about system audio devices being added, removed or becoming the
+default.
+*/
+
+mod event;
+pub use self::event::{DeviceEvent, DeviceEventKind, DeviceType};
+
+mod monitor;
+pub use self::monitor::Monitor;
+
+use std::time::Duration;
+use std::sync::mpsc::{channel, Receiver};
+
+/// Subscribe to `DeviceEvent`s, the callback is called from an internal
+/// thread.
+///
+/// The interval is used to poll the devices when the implementation can't
+/// report the events itself.
+pub fn subscribe<F>(interval: Duration, callback: F) -> Monitor
+	where F: FnMut(DeviceEvent) + Send + 'static
+{
+	Monitor::subscribe(interval, callback)
+}
+
+/// Subscribe to `DeviceEvent`s, delivering them through a channel.
+pub fn events(interval: Duration) -> (Monitor, Receiver<DeviceEvent>) {
+	let (sender, receiver) = channel();
+
+	let monitor = subscribe(interval, move |event| {
+		sender.send(event).ok();
+	});
+
+	(monitor, receiver)
+}
+
+/// Poll the devices at the given interval, even if the implementation could
+/// report the events itself.
+pub fn poll<F>(interval: Duration, callback: F) -> Monitor
+	where F: FnMut(DeviceEvent) + Send + 'static
+{
+	Monitor::poll(interval, callback)
+}
@@ -0,0 +1,253 @@
+use std::ptr;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Sender, RecvTimeoutError};
+
+use ffi::*;
+use sys::*;
+use {extension, listener, capture};
+use super::{DeviceEvent, DeviceEventKind, DeviceType};
+use super::event::{self, Callback};
+
+// Only one callback can be registered with the implementation.
+static NATIVE: AtomicBool = AtomicBool::new(false);
+
+/// A subscription to `DeviceEvent`s, events stop being delivered when it's
+/// dropped.
+///
+/// Events are reported by the implementation through ALC_SOFT_system_events
+/// when available, otherwise the devices are polled from a background thread.
+/// Either way the callback is called from a background thread, which resolves
+/// the device names of reported events by comparing the devices.
+pub struct Monitor {
+	inner: Inner,
+}
+
+enum Inner {
+	Native(Option<Box<Callback>>, Option<JoinHandle<()>>),
+	Polling(Option<Sender<()>>, Option<JoinHandle<()>>),
+}
+
+impl Monitor {
+	#[doc(hidden)]
+	pub fn subscribe<F>(interval: Duration, mut callback: F) -> Self
+		where F: FnMut(DeviceEvent) + Send + 'static
+	{
+		if !native() || NATIVE.swap(true, Ordering::SeqCst) {
+			return Monitor::poll(interval, callback);
+		}
+
+		let mut previous = Snapshot::take();
+		let (sender, received) = channel::<DeviceEvent>();
+
+		// The events are forwarded to a thread since the devices can't be
+		// enumerated from within the implementation's callback.
+		let mut notify: Box<Callback> = Box::new(Box::new(move |event| {
+			sender.send(event).ok();
+		}));
+
+		unsafe {
+			alcEventCallbackSOFT(Some(event::callback), &mut *notify as *mut Callback as *mut _);
+
+			let kinds = kinds();
+
+			if alcEventControlSOFT(kinds.len() as ALCsizei, kinds.as_ptr(), ALC_TRUE) != ALC_TRUE {
+				alcEventCallbackSOFT(None, ptr::null_mut());
+				NATIVE.store(false, Ordering::SeqCst);
+
+				return Monitor::poll(interval, callback);
+			}
+		}
+
+		let thread = thread::spawn(move || {
+			let mut pending = Vec::new();
+
+			// Dropping the forwarding callback ends the loop.
+			for reported in received.iter() {
+				let current = Snapshot::take();
+				current.diff(&previous, &mut |event| pending.push(event));
+				previous = current;
+
+				callback(resolve(reported, &mut pending));
+			}
+		});
+
+		Monitor { inner: Inner::Native(Some(notify), Some(thread)) }
+	}
+
+	#[doc(hidden)]
+	pub fn poll<F>(interval: Duration, mut callback: F) -> Self
+		where F: FnMut(DeviceEvent) + Send + 'static
+	{
+		let (stop, stopped) = channel::<()>();
+
+		let thread = thread::spawn(move || {
+			let mut previous = Snapshot::take();
+
+			// Dropping the sender wakes the thread up right away.
+			while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+				let current = Snapshot::take();
+				current.diff(&previous, &mut callback);
+				previous = current;
+			}
+		});
+
+		Monitor { inner: Inner::Polling(Some(stop), Some(thread)) }
+	}
+}
+
+impl Monitor {
+	/// Checks if the events are reported by the implementation instead of
+	/// being polled.
+	pub fn is_native(&self) -> bool {
+		match self.inner {
+			Inner::Native(..)  => true,
+			Inner::Polling(..) => false,
+		}
+	}
+}
+
+impl ::std::fmt::Debug for Monitor {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::monitor::Monitor("));
+		try!(f.write_str(if self.is_native() { "native" } else { "polling" }));
+		f.write_str(")")
+	}
+}
+
+impl Drop for Monitor {
+	fn drop(&mut self) {
+		match self.inner {
+			Inner::Native(ref mut callback, ref mut thread) => {
+				unsafe {
+					let kinds = kinds();
+
+					alcEventControlSOFT(kinds.len() as ALCsizei, kinds.as_ptr(), ALC_FALSE);
+					alcEventCallbackSOFT(None, ptr::null_mut());
+					NATIVE.store(false, Ordering::SeqCst);
+				}
+
+				callback.take();
+
+				if let Some(thread) = thread.take() {
+					thread.join().ok();
+				}
+			}
+
+			Inner::Polling(ref mut stop, ref mut thread) => {
+				stop.take();
+
+				if let Some(thread) = thread.take() {
+					thread.join().ok();
+				}
+			}
+		}
+	}
+}
+
+fn kinds() -> [ALCenum; 3] {
+	[DeviceEventKind::Added.into(), DeviceEventKind::Removed.into(), DeviceEventKind::DefaultChanged.into()]
+}
+
+// Check the implementation reports every kind of event for every type of
+// device.
+fn native() -> bool {
+	if !extension::device::is_supported("ALC_SOFT_system_events") {
+		return false;
+	}
+
+	kinds().iter().all(|&kind| [DeviceType::Playback, DeviceType::Capture].iter().all(|&device_type| unsafe {
+		alcEventIsSupportedSOFT(kind, device_type.into()) == ALC_EVENT_SUPPORTED_SOFT
+	}))
+}
+
+// Match a reported event with the earliest pending change of the same kind and
+// type of device, keeping the message the implementation provided.
+fn resolve(reported: DeviceEvent, pending: &mut Vec<DeviceEvent>) -> DeviceEvent {
+	let index = pending.iter().position(|event|
+		event.kind == reported.kind && event.device_type == reported.device_type);
+
+	match index {
+		Some(index) =>
+			DeviceEvent { message: reported.message, .. pending.remove(index) },
+
+		None =>
+			reported
+	}
+}
+
+struct Snapshot {
+	playback:         Vec<String>,
+	capture:          Vec<String>,
+	default_playback: Option<String>,
+	default_capture:  Option<String>,
+}
+
+impl Snapshot {
+	fn take() -> Self {
+		Snapshot {
+			playback:         listener::all_devices(),
+			capture:          capture::devices(),
+			default_playback: listener::default_device(),
+			default_capture:  capture::default_device(),
+		}
+	}
+
+	fn diff<F: FnMut(DeviceEvent)>(&self, previous: &Snapshot, callback: &mut F) {
+		changes(DeviceType::Playback, &previous.playback, &self.playback, &previous.default_playback, &self.default_playback, callback);
+		changes(DeviceType::Capture, &previous.capture, &self.capture, &previous.default_capture, &self.default_capture, callback);
+	}
+}
+
+fn changes<F: FnMut(DeviceEvent)>(device_type: DeviceType, before: &[String], after: &[String], default_before: &Option<String>, default_after: &Option<String>, callback: &mut F) {
+	for name in after.iter().filter(|name| !before.contains(name)) {
+		callback(DeviceEvent { kind: DeviceEventKind::Added, device_type: device_type, name: name.clone(), message: None });
+	}
+
+	for name in before.iter().filter(|name| !after.contains(name)) {
+		callback(DeviceEvent { kind: DeviceEventKind::Removed, device_type: device_type, name: name.clone(), message: None });
+	}
+
+	if default_before != default_after {
+		if let Some(ref name) = *default_after {
+			callback(DeviceEvent { kind: DeviceEventKind::DefaultChanged, device_type: device_type, name: name.clone(), message: None });
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Snapshot, resolve};
+	use monitor::{DeviceEvent, DeviceEventKind, DeviceType};
+
+	fn snapshot(playback: &[&str], default_playback: Option<&str>) -> Snapshot {
+		Snapshot {
+			playback:         playback.iter().map(|name| name.to_string()).collect(),
+			capture:          Vec::new(),
+			default_playback: default_playback.map(|name| name.to_string()),
+			default_capture:  None,
+		}
+	}
+
+	fn reported(kind: DeviceEventKind) -> DeviceEvent {
+		DeviceEvent { kind: kind, device_type: DeviceType::Playback, name: String::new(), message: Some("message".to_string()) }
+	}
+
+	#[test]
+	fn resolve_names() {
+		let mut pending = Vec::new();
+		snapshot(&["a", "b", "c"], Some("c")).diff(&snapshot(&["a"], Some("a")), &mut |event| pending.push(event));
+
+		let added = resolve(reported(DeviceEventKind::Added), &mut pending);
+		assert_eq!(added.name, "b");
+		assert_eq!(added.message, Some("message".to_string()));
+
+		let changed = resolve(reported(DeviceEventKind::DefaultChanged), &mut pending);
+		assert_eq!(changed.name, "c");
+
+		assert_eq!(resolve(reported(DeviceEventKind::Added), &mut pending).name, "c");
+		assert_eq!(resolve(reported(DeviceEventKind::Removed), &mut pending).name, "");
+		assert!(pending.is_empty());
+	}
+}
@@ -0,0 +1,271 @@
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::ops::Deref;
+
+use ::{Error, Sample};
+use super::{Stream, State};
+
+/// A trait for types providing sample data to a `Driver`.
+pub trait Provider {
+	/// The type of the samples.
+	type Sample: Sample + Copy + Default;
+
+	/// The number of channels of the provided data.
+	fn channels(&self) -> u16;
+
+	/// The sample rate of the provided data.
+	fn rate(&self) -> u32;
+
+	/// Fills the slice with interleaved samples, returning how many samples
+	/// were written, returning 0 marks the end of the data.
+	fn provide(&mut self, data: &mut [Self::Sample]) -> usize;
+}
+
+/// Represents the outcome of a `Driver` update.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Status {
+	/// The stream is playing.
+	Playing,
+
+	/// The stream has been paused.
+	Paused,
+
+	/// The stream ran out of data and has been restarted.
+	Underrun,
+
+	/// The provider has no more data and everything has been played.
+	Finished,
+}
+
+/// Drives a `Stream`, keeping its queue topped up from a `Provider`.
+///
+/// The `Driver` keeps a fixed pool of buffers in the `Stream` that are
+/// refilled as soon as they're processed, either call `update` regularly or
+/// move it to a background thread with `spawn`.
+pub struct Driver<'a, P: Provider> {
+	stream:   Stream<'a>,
+	provider: P,
+
+	data:      Vec<P::Sample>,
+	finished:  bool,
+	underruns: usize,
+}
+
+impl<'a, P: Provider> Driver<'a, P> {
+	#[doc(hidden)]
+	pub fn new(mut stream: Stream<'a>, provider: P, buffers: usize, frames: usize) -> Result<Self, Error> {
+		if buffers == 0 || frames == 0 {
+			return Err(Error::InvalidValue);
+		}
+
+		try!(stream.reserve(buffers));
+
+		let samples = frames * provider.channels() as usize;
+
+		Ok(Driver {
+			stream:   stream,
+			provider: provider,
+
+			data:      vec![Default::default(); samples],
+			finished:  false,
+			underruns: 0,
+		})
+	}
+}
+
+impl<'a, P: Provider> Driver<'a, P> {
+	/// Unqueues the processed buffers, refills them from the provider and
+	/// starts or restarts playback as needed.
+	pub fn update(&mut self) -> Result<Status, Error> {
+		try!(self.stream.recycle());
+
+		// Buffers still queued now may finish playing while the provider is busy.
+		let stale = self.stream.len();
+
+		while !self.finished && self.stream.free() > 0 {
+			let length = self.provider.provide(&mut self.data).min(self.data.len());
+
+			if length == 0 {
+				self.finished = true;
+				break;
+			}
+
+			try!(self.stream.push_into_free(self.provider.channels(), &self.data[.. length], self.provider.rate()));
+		}
+
+		let state = self.stream.state();
+
+		// A stopped source has played everything queued before the refill,
+		// unqueue it so restarting doesn't play it again.
+		if state == State::Stopped && stale > 0 {
+			try!(self.stream.recycle_up_to(stale));
+		}
+
+		match state {
+			State::Playing =>
+				Ok(Status::Playing),
+
+			State::Paused =>
+				Ok(Status::Paused),
+
+			_ if self.stream.len() == 0 =>
+				Ok(if self.finished { Status::Finished } else { Status::Playing }),
+
+			State::Stopped => {
+				self.underruns += 1;
+				self.stream.play();
+
+				Ok(Status::Underrun)
+			}
+
+			_ => {
+				self.stream.play();
+
+				Ok(Status::Playing)
+			}
+		}
+	}
+
+	/// Starts or resumes playback.
+	pub fn play(&mut self) {
+		self.stream.play();
+	}
+
+	/// Pauses playback, `update` will not resume it.
+	pub fn pause(&mut self) {
+		self.stream.pause();
+	}
+
+	/// The number of underruns since the `Driver` was created.
+	pub fn underruns(&self) -> usize {
+		self.underruns
+	}
+
+	/// Gets the provider.
+	pub fn provider(&self) -> &P {
+		&self.provider
+	}
+
+	/// Gets the provider mutably.
+	pub fn provider_mut(&mut self) -> &mut P {
+		&mut self.provider
+	}
+
+	/// Stops playback and returns the `Stream` and the provider.
+	pub fn into_inner(mut self) -> (Stream<'a>, P) {
+		self.stream.stop();
+		self.stream.recycle().ok();
+
+		(self.stream, self.provider)
+	}
+}
+
+impl<P> Driver<'static, P> where P: Provider + Send + 'static, P::Sample: Send {
+	/// Moves the `Driver` to a background thread updating it at the given
+	/// interval.
+	pub fn spawn(self, interval: Duration) -> BackgroundDriver<P> {
+		let stop = Arc::new(AtomicBool::new(false));
+		let (sender, receiver) = channel();
+
+		let thread = {
+			let stop = stop.clone();
+
+			thread::spawn(move || {
+				let mut driver = self;
+
+				while !stop.load(Ordering::Relaxed) {
+					match driver.update() {
+						Ok(Status::Playing) | Ok(Status::Paused) =>
+							(),
+
+						Ok(Status::Underrun) => {
+							sender.send(Ok(Status::Underrun)).ok();
+						}
+
+						Ok(Status::Finished) => {
+							sender.send(Ok(Status::Finished)).ok();
+							break;
+						}
+
+						Err(error) => {
+							sender.send(Err(error)).ok();
+							break;
+						}
+					}
+
+					thread::sleep(interval);
+				}
+
+				driver
+			})
+		};
+
+		BackgroundDriver {
+			stop:    stop,
+			thread:  Some(thread),
+			reports: receiver,
+		}
+	}
+}
+
+impl<'a, P: Provider> Deref for Driver<'a, P> {
+	type Target = Stream<'a>;
+
+	fn deref(&self) -> &<Self as Deref>::Target {
+		&self.stream
+	}
+}
+
+impl<'a, P: Provider> ::std::fmt::Debug for Driver<'a, P> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::source::Driver("));
+		try!(f.write_str(&format!("{}; ", unsafe { self.stream.id() })));
+		try!(f.write_str(&format!("queued={} ", self.stream.len())));
+		try!(f.write_str(&format!("free={} ", self.stream.free())));
+		try!(f.write_str(&format!("underruns={}", self.underruns)));
+		f.write_str(")")
+	}
+}
+
+/// A `Driver` running on a background thread.
+///
+/// Underruns, the end of the data and errors are reported through `reports`,
+/// the thread stops on the end of the data or on error.
+pub struct BackgroundDriver<P: Provider + Send + 'static> {
+	stop:    Arc<AtomicBool>,
+	thread:  Option<JoinHandle<Driver<'static, P>>>,
+	reports: Receiver<Result<Status, Error>>,
+}
+
+impl<P> BackgroundDriver<P> where P: Provider + Send + 'static {
+	/// The receiving end of the reports.
+	pub fn reports(&self) -> &Receiver<Result<Status, Error>> {
+		&self.reports
+	}
+
+	/// Stops the background thread and returns the `Driver`.
+	pub fn stop(mut self) -> Driver<'static, P> {
+		self.stop.store(true, Ordering::Relaxed);
+
+		match self.thread.take().unwrap().join() {
+			Ok(driver) =>
+				driver,
+
+			Err(error) =>
+				::std::panic::resume_unwind(error)
+		}
+	}
+}
+
+impl<P> Drop for BackgroundDriver<P> where P: Provider + Send + 'static {
+	fn drop(&mut self) {
+		self.stop.store(true, Ordering::Relaxed);
+
+		if let Some(thread) = self.thread.take() {
+			thread.join().ok();
+		}
+	}
+}
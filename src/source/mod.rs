@@ -5,6 +5,9 @@ Helpers related to `Source`.
 mod stream;
 pub use self::stream::Stream;
 
+mod driver;
+pub use self::driver::{Driver, BackgroundDriver, Provider, Status};
+
 mod state;
 pub use self::state::State;
 
@@ -12,4 +15,4 @@ mod offset;
 pub use self::offset::Offset;
 
 mod source;
-pub use self::source::Source;
+pub use self::source::{Source, Handle};
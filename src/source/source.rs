@@ -1,37 +1,75 @@
 use std::mem;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use ffi::*;
-use ::{Error, Vector, Position, Direction, Velocity, Buffer};
+use sys::*;
+use ::{Error, Vector, Position, Direction, Velocity, Orientation, DistanceModel, Buffer, extension};
+use ::util::duration;
+use efx::{EffectSlot, Filter};
+use listener::Current;
 use super::{State, Offset, Stream};
 
+/// An opaque reference to a `Source`, used to match `Event`s against it.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Handle(ALuint);
+
+impl Handle {
+	#[doc(hidden)]
+	pub unsafe fn new(id: ALuint) -> Self {
+		Handle(id)
+	}
+}
+
 /// Represents a static source.
 ///
 /// Static sources will have buffers queued that will never change, they're
 /// useful for sound effects and similar.
 #[derive(PartialEq, Eq)]
 pub struct Source<'a> {
-	id: ALuint,
+	id:      ALuint,
+	context: *mut ALCcontext,
 
 	_marker: PhantomData<&'a ()>,
 }
 
+unsafe impl<'a> Send for Source<'a> { }
+unsafe impl<'a> Sync for Source<'a> { }
+
 impl<'a> Source<'a> {
 	#[doc(hidden)]
 	pub unsafe fn new() -> Result<Self, Error> {
 		let mut id = 0;
 		al_try!(alGenSources(1, &mut id));
 
-		Ok(Source { id: id, _marker: PhantomData })
+		Ok(Source { id: id, context: Current::get(), _marker: PhantomData })
 	}
 
 	#[doc(hidden)]
 	pub unsafe fn id(&self) -> ALuint {
 		self.id
 	}
+
+	// Make the owning context current for the duration of a call, source names
+	// are only valid on the context they were created on.
+	#[doc(hidden)]
+	pub fn scoped(&self) -> Result<Option<Current>, Error> {
+		Current::scope(self.context)
+	}
+}
+
+impl<'a> PartialEq<Handle> for Source<'a> {
+	fn eq(&self, other: &Handle) -> bool {
+		self.id == other.0
+	}
 }
 
 impl<'a> Source<'a> {
+	/// Gets a handle to compare `Event`s against.
+	pub fn handle(&self) -> Handle {
+		Handle(self.id)
+	}
+
 	/// Returns a streaming source.
 	pub fn stream(self) -> Stream<'a> {
 		unsafe {
@@ -41,6 +79,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the state.
 	pub fn state(&self) -> State {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut state = 0;
 			alGetSourcei(self.id, AL_SOURCE_STATE, &mut state);
@@ -51,6 +91,8 @@ impl<'a> Source<'a> {
 
 	/// Plays the source.
 	pub fn play(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcePlay(self.id);
 		}
@@ -58,6 +100,8 @@ impl<'a> Source<'a> {
 
 	/// Pauses the source.
 	pub fn pause(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcePause(self.id);
 		}
@@ -65,6 +109,8 @@ impl<'a> Source<'a> {
 
 	/// Stops the source.
 	pub fn stop(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourceStop(self.id);
 		}
@@ -72,6 +118,8 @@ impl<'a> Source<'a> {
 
 	/// Rewinds the source.
 	pub fn rewind(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourceRewind(self.id);
 		}
@@ -79,6 +127,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the cone inner angle.
 	pub fn cone_inner_angle(&self) -> f32 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0.0;
 			alGetSourcef(self.id, AL_CONE_INNER_ANGLE, &mut value);
@@ -89,6 +139,8 @@ impl<'a> Source<'a> {
 
 	/// Sets the cone inner angle.
 	pub fn set_cone_inner_angle(&mut self, value: f32) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcef(self.id, AL_CONE_INNER_ANGLE, value as ALfloat);
 		}
@@ -96,6 +148,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the cone outer angle.
 	pub fn cone_outer_angle(&self) -> f32 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0.0;
 			alGetSourcef(self.id, AL_CONE_OUTER_ANGLE, &mut value);
@@ -106,13 +160,135 @@ impl<'a> Source<'a> {
 
 	/// Sets the cone outer angle.
 	pub fn set_cone_outer_angle(&mut self, value: f32) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcef(self.id, AL_CONE_OUTER_ANGLE, value as ALfloat);
 		}
 	}
 
+	/// Gets the gain outside the cone.
+	pub fn cone_outer_gain(&self) -> f32 {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = 0.0;
+			alGetSourcef(self.id, AL_CONE_OUTER_GAIN, &mut value);
+
+			value as f32
+		}
+	}
+
+	/// Sets the gain outside the cone.
+	pub fn set_cone_outer_gain(&mut self, value: f32) {
+		let _current = self.scoped();
+
+		unsafe {
+			alSourcef(self.id, AL_CONE_OUTER_GAIN, value as ALfloat);
+		}
+	}
+
+	/// Gets the reference distance.
+	pub fn reference_distance(&self) -> f32 {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = 0.0;
+			alGetSourcef(self.id, AL_REFERENCE_DISTANCE, &mut value);
+
+			value as f32
+		}
+	}
+
+	/// Sets the reference distance, the distance at which the gain is not
+	/// attenuated.
+	pub fn set_reference_distance(&mut self, value: f32) {
+		let _current = self.scoped();
+
+		unsafe {
+			alSourcef(self.id, AL_REFERENCE_DISTANCE, value as ALfloat);
+		}
+	}
+
+	/// Gets the rolloff factor.
+	pub fn rolloff_factor(&self) -> f32 {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = 0.0;
+			alGetSourcef(self.id, AL_ROLLOFF_FACTOR, &mut value);
+
+			value as f32
+		}
+	}
+
+	/// Sets the rolloff factor.
+	pub fn set_rolloff_factor(&mut self, value: f32) {
+		let _current = self.scoped();
+
+		unsafe {
+			alSourcef(self.id, AL_ROLLOFF_FACTOR, value as ALfloat);
+		}
+	}
+
+	/// Gets the maximum distance.
+	pub fn max_distance(&self) -> f32 {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = 0.0;
+			alGetSourcef(self.id, AL_MAX_DISTANCE, &mut value);
+
+			value as f32
+		}
+	}
+
+	/// Sets the maximum distance, beyond which the gain is no longer
+	/// attenuated.
+	pub fn set_max_distance(&mut self, value: f32) {
+		let _current = self.scoped();
+
+		unsafe {
+			alSourcef(self.id, AL_MAX_DISTANCE, value as ALfloat);
+		}
+	}
+
+	/// Gets the distance model.
+	pub fn distance_model(&self) -> Result<DistanceModel, Error> {
+		let _current = try!(self.scoped());
+
+		if !extension::is_supported("AL_EXT_source_distance_model") {
+			return Err(Error::Unsupported("AL_EXT_source_distance_model"));
+		}
+
+		unsafe {
+			let mut value = 0;
+			al_try!(alGetSourcei(self.id, AL_DISTANCE_MODEL, &mut value));
+
+			Ok(DistanceModel::from(value))
+		}
+	}
+
+	/// Sets the distance model, it's only used if enabled on the `Listener`
+	/// with `enable_source_distance_model`.
+	pub fn set_distance_model(&mut self, value: DistanceModel) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		if !extension::is_supported("AL_EXT_source_distance_model") {
+			return Err(Error::Unsupported("AL_EXT_source_distance_model"));
+		}
+
+		unsafe {
+			al_try!(alSourcei(self.id, AL_DISTANCE_MODEL, value.into()));
+		}
+
+		Ok(())
+	}
+
 	/// Gets the pitch.
 	pub fn pitch(&self) -> f32 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0.0;
 			alGetSourcef(self.id, AL_PITCH, &mut value);
@@ -123,6 +299,8 @@ impl<'a> Source<'a> {
 
 	/// Sets the pitch.
 	pub fn set_pitch(&mut self, value: f32) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcef(self.id, AL_PITCH, value as ALfloat);
 		}
@@ -130,6 +308,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the position.
 	pub fn position(&self) -> Position {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = Position(Vector { x: 0.0, y: 0.0, z: 0.0 });
 			alGetSourcefv(self.id, AL_POSITION, mem::transmute(&mut value));
@@ -140,6 +320,8 @@ impl<'a> Source<'a> {
 
 	/// Sets the position.
 	pub fn set_position(&self, value: &Position) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcefv(self.id, AL_POSITION, mem::transmute(value));
 		}
@@ -147,6 +329,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the direction.
 	pub fn direction(&self) -> Direction {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = Direction(Vector { x: 0.0, y: 0.0, z: 0.0 });
 			alGetSourcefv(self.id, AL_DIRECTION, mem::transmute(&mut value));
@@ -157,6 +341,8 @@ impl<'a> Source<'a> {
 
 	/// Sets the direction.
 	pub fn set_direction(&self, value: &Direction) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcefv(self.id, AL_DIRECTION, mem::transmute(value));
 		}
@@ -164,6 +350,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the velocity.
 	pub fn velocity(&self) -> Velocity {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = Velocity(Vector { x: 0.0, y: 0.0, z: 0.0 });
 			alGetSourcefv(self.id, AL_VELOCITY, mem::transmute(&mut value));
@@ -174,13 +362,38 @@ impl<'a> Source<'a> {
 
 	/// Sets the velocity.
 	pub fn set_velocity(&self, value: &Velocity) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcefv(self.id, AL_VELOCITY, mem::transmute(value));
 		}
 	}
 
+	/// Gets the orientation of the soundfield, used by ambisonic `Buffer`s.
+	pub fn orientation(&self) -> Orientation {
+		let _current = self.scoped();
+
+		unsafe {
+			let mut value = Orientation(Vector { x: 0.0, y: 0.0, z: 0.0 }, Vector { x: 0.0, y: 0.0, z: 0.0 });
+			alGetSourcefv(self.id, AL_ORIENTATION, mem::transmute(&mut value));
+
+			value
+		}
+	}
+
+	/// Sets the orientation of the soundfield, used by ambisonic `Buffer`s.
+	pub fn set_orientation(&mut self, value: &Orientation) {
+		let _current = self.scoped();
+
+		unsafe {
+			alSourcefv(self.id, AL_ORIENTATION, mem::transmute(value));
+		}
+	}
+
 	/// Checks if the source is relative.
 	pub fn is_relative(&self) -> bool {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0;
 			alGetSourcei(self.id, AL_SOURCE_RELATIVE, &mut value);
@@ -191,6 +404,8 @@ impl<'a> Source<'a> {
 
 	/// Makes the source relative.
 	pub fn enable_relative(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcei(self.id, AL_SOURCE_RELATIVE, AL_TRUE as ALint);
 		}
@@ -198,6 +413,8 @@ impl<'a> Source<'a> {
 
 	/// Makes the source absolute.
 	pub fn disable_relative(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcei(self.id, AL_SOURCE_RELATIVE, AL_FALSE as ALint);
 		}
@@ -205,6 +422,8 @@ impl<'a> Source<'a> {
 
 	/// Checks if the source is looping.
 	pub fn is_looping(&self) -> bool {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0;
 			alGetSourcei(self.id, AL_LOOPING, &mut value);
@@ -215,6 +434,8 @@ impl<'a> Source<'a> {
 
 	/// Makes the source looping.
 	pub fn enable_looping(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcei(self.id, AL_LOOPING, AL_TRUE as ALint);
 		}
@@ -222,6 +443,8 @@ impl<'a> Source<'a> {
 
 	/// Makes the source one-shot.
 	pub fn disable_looping(&mut self) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcei(self.id, AL_LOOPING, AL_FALSE as ALint);
 		}
@@ -229,6 +452,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the gain.
 	pub fn gain(&self) -> f32 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0.0;
 			alGetSourcef(self.id, AL_GAIN, &mut value);
@@ -239,6 +464,8 @@ impl<'a> Source<'a> {
 
 	/// Sets the gain.
 	pub fn set_gain(&mut self, value: f32) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcef(self.id, AL_GAIN, value as ALfloat);
 		}
@@ -246,6 +473,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the minimum gain.
 	pub fn min_gain(&self) -> f32 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0.0;
 			alGetSourcef(self.id, AL_MIN_GAIN, &mut value);
@@ -256,6 +485,8 @@ impl<'a> Source<'a> {
 
 	/// Sets the minimum gain.
 	pub fn set_min_gain(&mut self, value: f32) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcef(self.id, AL_MIN_GAIN, value as ALfloat);
 		}
@@ -263,6 +494,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the maximum gain.
 	pub fn max_gain(&self) -> f32 {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0.0;
 			alGetSourcef(self.id, AL_MAX_GAIN, &mut value);
@@ -273,6 +506,8 @@ impl<'a> Source<'a> {
 
 	/// Sets the minimum gain.
 	pub fn set_max_gain(&mut self, value: f32) {
+		let _current = self.scoped();
+
 		unsafe {
 			alSourcef(self.id, AL_MAX_GAIN, value as ALfloat);
 		}
@@ -280,6 +515,8 @@ impl<'a> Source<'a> {
 
 	/// Gets the offset in the specified representation.
 	pub fn offset(&self, offset: Offset) -> Offset {
+		let _current = self.scoped();
+
 		unsafe {
 			let kind = match offset {
 				Offset::Seconds(..) =>
@@ -308,8 +545,48 @@ impl<'a> Source<'a> {
 		}
 	}
 
+	/// Gets the offset in samples, with sub-sample precision, paired with the
+	/// latency of the device at the time it was sampled.
+	pub fn offset_latency(&self) -> Result<(f64, Duration), Error> {
+		let _current = try!(self.scoped());
+
+		if !extension::is_supported("AL_SOFT_source_latency") {
+			return Err(Error::Unsupported("AL_SOFT_source_latency"));
+		}
+
+		unsafe {
+			let mut value = [0; 2];
+			al_try!(alGetSourcei64vSOFT(self.id, AL_SAMPLE_OFFSET_LATENCY_SOFT, value.as_mut_ptr()));
+
+			Ok((fixed(value[0]), duration(value[1])))
+		}
+	}
+
+	/// Gets the offset in samples, with sub-sample precision, paired with the
+	/// device clock at the time it was sampled.
+	pub fn offset_clock(&self) -> Result<(f64, Duration), Error> {
+		let _current = try!(self.scoped());
+
+		if !extension::is_supported("AL_SOFT_source_latency") {
+			return Err(Error::Unsupported("AL_SOFT_source_latency"));
+		}
+
+		unsafe {
+			if !extension::device::is_supported_by(&alcGetContextsDevice(self.context), "ALC_SOFT_device_clock") {
+				return Err(Error::Unsupported("ALC_SOFT_device_clock"));
+			}
+
+			let mut value = [0; 2];
+			al_try!(alGetSourcei64vSOFT(self.id, AL_SAMPLE_OFFSET_CLOCK_SOFT, value.as_mut_ptr()));
+
+			Ok((fixed(value[0]), duration(value[1])))
+		}
+	}
+
 	/// Sets the offset.
 	pub fn set_offset(&mut self, value: Offset) {
+		let _current = self.scoped();
+
 		unsafe {
 			match value {
 				Offset::Seconds(value) =>
@@ -326,6 +603,8 @@ impl<'a> Source<'a> {
 
 	/// Gets how many buffers are queued.
 	pub fn queued(&self) -> usize {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0;
 			alGetSourcei(self.id, AL_BUFFERS_QUEUED, &mut value);
@@ -336,6 +615,8 @@ impl<'a> Source<'a> {
 
 	/// Gets how many buffers have been processed.
 	pub fn processed(&self) -> usize {
+		let _current = self.scoped();
+
 		unsafe {
 			let mut value = 0;
 			alGetSourcei(self.id, AL_BUFFERS_PROCESSED, &mut value);
@@ -344,8 +625,70 @@ impl<'a> Source<'a> {
 		}
 	}
 
+	/// Sets the filter applied to the direct path, `None` removes it.
+	///
+	/// The `Filter` parameters are copied, set it again to apply changes.
+	pub fn set_direct_filter(&mut self, filter: Option<&'a Filter>) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			let filter = filter.map(|f| f.id() as ALint).unwrap_or(AL_FILTER_NULL);
+			al_try!(alSourcei(self.id, AL_DIRECT_FILTER, filter));
+		}
+
+		Ok(())
+	}
+
+	/// Sends the output to the given slot through the auxiliary send at the
+	/// given index, optionally applying a filter.
+	pub fn set_send(&mut self, index: usize, slot: &'a EffectSlot, filter: Option<&'a Filter>) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			let filter = filter.map(|f| f.id() as ALint).unwrap_or(AL_FILTER_NULL);
+			al_try!(alSource3i(self.id, AL_AUXILIARY_SEND_FILTER, slot.id() as ALint, index as ALint, filter));
+		}
+
+		Ok(())
+	}
+
+	/// Disconnects the auxiliary send at the given index.
+	pub fn clear_send(&mut self, index: usize) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			al_try!(alSource3i(self.id, AL_AUXILIARY_SEND_FILTER, AL_EFFECTSLOT_NULL, index as ALint, AL_FILTER_NULL));
+		}
+
+		Ok(())
+	}
+
+	/// Sets the buffer to play, replacing anything queued.
+	pub fn set_buffer(&mut self, buffer: &'a Buffer) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			al_try!(alSourcei(self.id, AL_BUFFER, buffer.id() as ALint));
+		}
+
+		Ok(())
+	}
+
+	/// Removes the buffer and anything queued, the source must be stopped.
+	pub fn clear_buffer(&mut self) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		unsafe {
+			al_try!(alSourcei(self.id, AL_BUFFER, AL_NONE));
+		}
+
+		Ok(())
+	}
+
 	/// Pushes a buffer into the source's queue.
 	pub fn push<'b: 'a>(&'b mut self, buffer: &'b Buffer<'b>) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
 		unsafe {
 			al_try!(alSourceQueueBuffers(self.id, 1, &buffer.id()));
 
@@ -354,6 +697,11 @@ impl<'a> Source<'a> {
 	}
 }
 
+/// Converts a 32.32 fixed point value.
+fn fixed(value: i64) -> f64 {
+	value as f64 / (1u64 << 32) as f64
+}
+
 impl<'a> ::std::fmt::Debug for Source<'a> {
 	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
 		try!(f.write_str("openal::Source("));
@@ -364,6 +712,8 @@ impl<'a> ::std::fmt::Debug for Source<'a> {
 
 impl<'a> Drop for Source<'a> {
 	fn drop(&mut self) {
+		let _current = self.scoped();
+
 		if self.state() != State::Playing && self.state() != State::Paused {
 			self.stop();
 		}
@@ -3,14 +3,19 @@ use std::collections::VecDeque;
 
 use ffi::*;
 use ::{Error, Source, Sample, Buffer};
+use super::{Driver, Provider};
 
 /// Represents a streaming source for the `Listener`.
 ///
 /// Stream sources will have buffers getting in and out of them, they're useful
 /// for playing music and other kind of streaming sounds.
+///
+/// Processed buffers are kept in a free list and refilled by later pushes, so
+/// a long running stream doesn't keep generating and deleting buffers.
 pub struct Stream<'a> {
-	source:  Source<'a>,
-	buffers: VecDeque<Buffer<'a>>,
+	source: Source<'a>,
+	queued: VecDeque<Buffer<'a>>,
+	free:   Vec<Buffer<'a>>,
 }
 
 impl<'a> Stream<'a> {
@@ -19,37 +24,101 @@ impl<'a> Stream<'a> {
 		source.disable_looping();
 
 		Stream {
-			source:  source,
-			buffers: VecDeque::new(),
+			source: source,
+			queued: VecDeque::new(),
+			free:   Vec::new(),
 		}
 	}
 }
 
 impl<'a> Stream<'a> {
-	/// Pushes the data into the source.
+	/// Returns a `Driver` filling the stream from the provider, using the given
+	/// number of buffers holding the given number of sample frames each.
+	pub fn driver<P: Provider>(self, provider: P, buffers: usize, frames: usize) -> Result<Driver<'a, P>, Error> {
+		Driver::new(self, provider, buffers, frames)
+	}
+
+	/// Gets the number of queued buffers.
+	pub fn len(&self) -> usize {
+		self.queued.len()
+	}
+
+	/// Gets the number of buffers in the free list.
+	pub fn free(&self) -> usize {
+		self.free.len()
+	}
+
+	/// Adds empty buffers to the free list until it holds at least the given
+	/// number of buffers.
+	pub fn reserve(&mut self, count: usize) -> Result<(), Error> {
+		let _current = try!(self.scoped());
+
+		while self.free.len() < count {
+			self.free.push(try!(unsafe { Buffer::empty() }));
+		}
+
+		Ok(())
+	}
+
+	/// Pushes the data into the source, reusing a buffer from the free list if
+	/// there is one.
 	pub fn push<T: Sample>(&mut self, channels: u16, data: &[T], rate: u32) -> Result<(), Error> {
-		let buffer = try!(unsafe { Buffer::new(channels, data, rate) });
-		try!(self.clear());
+		try!(self.recycle());
+
+		if self.free.is_empty() {
+			try!(self.reserve(1));
+		}
+
+		self.push_into_free(channels, data, rate).map(|_| ())
+	}
+
+	/// Pushes the data into a buffer from the free list, never generating a new
+	/// one, returns `false` if the free list is empty.
+	pub fn push_into_free<T: Sample>(&mut self, channels: u16, data: &[T], rate: u32) -> Result<bool, Error> {
+		let _current = try!(self.scoped());
+
+		let mut buffer = match self.free.pop() {
+			Some(buffer) =>
+				buffer,
+
+			None =>
+				return Ok(false)
+		};
+
+		if let Err(error) = unsafe { buffer.fill(channels, data, rate) } {
+			self.free.push(buffer);
+
+			return Err(error);
+		}
 
 		unsafe {
-			al_try!(alSourceQueueBuffers(self.id(), 1, &buffer.id()));
+			alSourceQueueBuffers(self.id(), 1, &buffer.id());
 		}
 
-		self.buffers.push_back(buffer);
+		if let Some(error) = Error::last() {
+			self.free.push(buffer);
 
-		Ok(())
+			Err(error)
+		}
+		else {
+			self.queued.push_back(buffer);
+
+			Ok(true)
+		}
 	}
 
 	/// Removes a buffer from the front of the queue.
-	pub fn pop(&mut self) -> Result<Buffer, Error> {
-		if let Some(buffer) = self.buffers.pop_back() {
+	pub fn pop(&mut self) -> Result<Buffer<'a>, Error> {
+		let _current = try!(self.scoped());
+
+		if let Some(buffer) = self.queued.pop_front() {
 			unsafe {
 				let mut tmp = 0;
 				alSourceUnqueueBuffers(self.id(), 1 as ALsizei, &mut tmp);
 			}
 
 			if let Some(error) = Error::last() {
-				self.buffers.push_front(buffer);
+				self.queued.push_front(buffer);
 
 				Err(error)
 			}
@@ -62,9 +131,18 @@ impl<'a> Stream<'a> {
 		}
 	}
 
-	/// Removes the processed buffers from the queue.
-	pub fn clear(&mut self) -> Result<(), Error> {
-		let processed = self.processed();
+	/// Moves the processed buffers from the queue into the free list,
+	/// returning how many were moved.
+	pub fn recycle(&mut self) -> Result<usize, Error> {
+		self.recycle_up_to(usize::max_value())
+	}
+
+	/// Moves at most the given number of processed buffers from the front of
+	/// the queue into the free list, returning how many were moved.
+	pub fn recycle_up_to(&mut self, count: usize) -> Result<usize, Error> {
+		let _current = try!(self.scoped());
+
+		let processed = self.processed().min(count);
 
 		if processed > 0 {
 			unsafe {
@@ -73,11 +151,18 @@ impl<'a> Stream<'a> {
 			}
 
 			for _ in 0 .. processed {
-				self.buffers.pop_front();
+				if let Some(buffer) = self.queued.pop_front() {
+					self.free.push(buffer);
+				}
 			}
 		}
 
-		Ok(())
+		Ok(processed)
+	}
+
+	/// Removes the processed buffers from the queue.
+	pub fn clear(&mut self) -> Result<(), Error> {
+		self.recycle().map(|_| ())
 	}
 
 	#[doc(hidden)]
@@ -109,7 +194,8 @@ impl<'a> ::std::fmt::Debug for Stream<'a> {
 	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
 		try!(f.write_str("openal::source::Stream("));
 		try!(f.write_str(&format!("{}; ", unsafe { self.id() })));
-		try!(f.write_str(&format!("len={}", self.buffers.len())));
+		try!(f.write_str(&format!("len={} ", self.queued.len())));
+		try!(f.write_str(&format!("free={}", self.free.len())));
 		f.write_str(")")
 	}
 }
@@ -0,0 +1,415 @@
+/*!
+Extension enumerations and entry points not exposed by `openal-sys`.
+
+Extension functions are resolved lazily through `alGetProcAddress` and
+`alcGetProcAddress`, callers must make sure the relevant extension is
+supported before calling any of them.
+*/
+
+#![allow(dead_code)]
+
+use std::ptr;
+
+use ffi::*;
+
+macro_rules! entry {
+	($loader:ident; $(pub fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)*;)*) => ($(
+		#[allow(non_snake_case)]
+		pub unsafe fn $name($($arg: $ty),*) $(-> $ret)* {
+			use std::mem;
+			use std::sync::atomic::{AtomicUsize, Ordering};
+
+			static ADDRESS: AtomicUsize = AtomicUsize::new(0);
+
+			let mut address = ADDRESS.load(Ordering::Relaxed);
+
+			if address == 0 {
+				address = $loader(concat!(stringify!($name), "\0"));
+				ADDRESS.store(address, Ordering::Relaxed);
+			}
+
+			if address == 0 {
+				panic!(concat!("missing OpenAL entry point ", stringify!($name)));
+			}
+
+			mem::transmute::<usize, unsafe extern "C" fn($($ty),*) $(-> $ret)*>(address)($($arg),*)
+		}
+	)*)
+}
+
+unsafe fn al(name: &str) -> usize {
+	alGetProcAddress(name.as_ptr() as *const _) as usize
+}
+
+unsafe fn alc(name: &str) -> usize {
+	alcGetProcAddress(ptr::null(), name.as_ptr() as *const _) as usize
+}
+
+// Misspelled as `AL_ROLLOF_FACTOR` in `openal-sys`.
+pub const AL_ROLLOFF_FACTOR: ALenum = 0x1021;
+
+// AL_EXT_source_distance_model
+pub const AL_SOURCE_DISTANCE_MODEL: ALenum = 0x0200;
+
+// AL_EXT_FLOAT32
+pub const AL_FORMAT_MONO_FLOAT32:   ALenum = 0x10010;
+pub const AL_FORMAT_STEREO_FLOAT32: ALenum = 0x10011;
+
+// AL_EXT_DOUBLE
+pub const AL_FORMAT_MONO_DOUBLE_EXT:   ALenum = 0x10012;
+pub const AL_FORMAT_STEREO_DOUBLE_EXT: ALenum = 0x10013;
+
+// AL_EXT_MCFORMATS
+pub const AL_FORMAT_QUAD8:    ALenum = 0x1204;
+pub const AL_FORMAT_QUAD16:   ALenum = 0x1205;
+pub const AL_FORMAT_QUAD32:   ALenum = 0x1206;
+pub const AL_FORMAT_REAR8:    ALenum = 0x1207;
+pub const AL_FORMAT_REAR16:   ALenum = 0x1208;
+pub const AL_FORMAT_REAR32:   ALenum = 0x1209;
+pub const AL_FORMAT_51CHN8:   ALenum = 0x120A;
+pub const AL_FORMAT_51CHN16:  ALenum = 0x120B;
+pub const AL_FORMAT_51CHN32:  ALenum = 0x120C;
+pub const AL_FORMAT_61CHN8:   ALenum = 0x120D;
+pub const AL_FORMAT_61CHN16:  ALenum = 0x120E;
+pub const AL_FORMAT_61CHN32:  ALenum = 0x120F;
+pub const AL_FORMAT_71CHN8:   ALenum = 0x1210;
+pub const AL_FORMAT_71CHN16:  ALenum = 0x1211;
+pub const AL_FORMAT_71CHN32:  ALenum = 0x1212;
+
+// AL_EXT_BFORMAT
+pub const AL_FORMAT_BFORMAT2D_8:       ALenum = 0x20021;
+pub const AL_FORMAT_BFORMAT2D_16:      ALenum = 0x20022;
+pub const AL_FORMAT_BFORMAT2D_FLOAT32: ALenum = 0x20023;
+pub const AL_FORMAT_BFORMAT3D_8:       ALenum = 0x20031;
+pub const AL_FORMAT_BFORMAT3D_16:      ALenum = 0x20032;
+pub const AL_FORMAT_BFORMAT3D_FLOAT32: ALenum = 0x20033;
+
+// AL_SOFT_bformat_ex
+pub const AL_AMBISONIC_LAYOUT_SOFT:  ALenum = 0x1997;
+pub const AL_AMBISONIC_SCALING_SOFT: ALenum = 0x1998;
+
+pub const AL_FUMA_SOFT: ALenum = 0x0000;
+pub const AL_ACN_SOFT:  ALenum = 0x0001;
+pub const AL_SN3D_SOFT: ALenum = 0x0001;
+pub const AL_N3D_SOFT:  ALenum = 0x0002;
+
+// AL_SOFT_bformat_hoa
+pub const AL_UNPACK_AMBISONIC_ORDER_SOFT: ALenum = 0x199D;
+
+// AL_SOFT_source_latency
+pub type ALint64SOFT = i64;
+
+pub const AL_SAMPLE_OFFSET_LATENCY_SOFT: ALenum = 0x1200;
+pub const AL_SEC_OFFSET_LATENCY_SOFT:    ALenum = 0x1201;
+
+entry! { al;
+	pub fn alGetSourcedvSOFT(source: ALuint, param: ALenum, values: *mut ALdouble);
+	pub fn alGetSourcei64vSOFT(source: ALuint, param: ALenum, values: *mut ALint64SOFT);
+}
+
+// ALC_SOFT_device_clock
+pub type ALCint64SOFT = i64;
+
+pub const AL_SAMPLE_OFFSET_CLOCK_SOFT: ALenum = 0x1202;
+pub const AL_SEC_OFFSET_CLOCK_SOFT:    ALenum = 0x1203;
+
+pub const ALC_DEVICE_CLOCK_SOFT:         ALCenum = 0x1600;
+pub const ALC_DEVICE_LATENCY_SOFT:       ALCenum = 0x1601;
+pub const ALC_DEVICE_CLOCK_LATENCY_SOFT: ALCenum = 0x1602;
+
+entry! { alc;
+	pub fn alcGetInteger64vSOFT(device: *mut ALCdevice, param: ALCenum, size: ALCsizei, values: *mut ALCint64SOFT);
+}
+
+// AL_SOFT_events
+#[allow(non_camel_case_types)]
+pub type ALEVENTPROCSOFT = Option<unsafe extern "C" fn(kind: ALenum, object: ALuint, param: ALuint, length: ALsizei, message: *const ALchar, user: *mut ALvoid)>;
+
+pub const AL_EVENT_CALLBACK_FUNCTION_SOFT:         ALenum = 0x19A2;
+pub const AL_EVENT_CALLBACK_USER_PARAM_SOFT:       ALenum = 0x19A3;
+pub const AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT:     ALenum = 0x19A4;
+pub const AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT: ALenum = 0x19A5;
+pub const AL_EVENT_TYPE_DISCONNECTED_SOFT:         ALenum = 0x19A6;
+
+entry! { al;
+	pub fn alEventControlSOFT(count: ALsizei, kinds: *const ALenum, enable: ALboolean);
+	pub fn alEventCallbackSOFT(callback: ALEVENTPROCSOFT, user: *mut ALvoid);
+}
+
+// AL_SOFT_callback_buffer
+#[allow(non_camel_case_types)]
+pub type ALBUFFERCALLBACKTYPESOFT = Option<unsafe extern "C" fn(user: *mut ALvoid, data: *mut ALvoid, size: ALsizei) -> ALsizei>;
+
+pub const AL_BUFFER_CALLBACK_FUNCTION_SOFT:   ALenum = 0x19A0;
+pub const AL_BUFFER_CALLBACK_USER_PARAM_SOFT: ALenum = 0x19A1;
+
+entry! { al;
+	pub fn alBufferCallbackSOFT(buffer: ALuint, format: ALenum, frequency: ALsizei, callback: ALBUFFERCALLBACKTYPESOFT, user: *mut ALvoid);
+}
+
+// AL_SOFT_loop_points
+pub const AL_LOOP_POINTS_SOFT: ALenum = 0x2015;
+
+// ALC_SOFT_loopback
+pub const ALC_BYTE_SOFT:           ALCenum = 0x1400;
+pub const ALC_UNSIGNED_BYTE_SOFT:  ALCenum = 0x1401;
+pub const ALC_SHORT_SOFT:          ALCenum = 0x1402;
+pub const ALC_UNSIGNED_SHORT_SOFT: ALCenum = 0x1403;
+pub const ALC_INT_SOFT:            ALCenum = 0x1404;
+pub const ALC_UNSIGNED_INT_SOFT:   ALCenum = 0x1405;
+pub const ALC_FLOAT_SOFT:          ALCenum = 0x1406;
+
+pub const ALC_MONO_SOFT:    ALCenum = 0x1500;
+pub const ALC_STEREO_SOFT:  ALCenum = 0x1501;
+pub const ALC_QUAD_SOFT:    ALCenum = 0x1503;
+pub const ALC_5POINT1_SOFT: ALCenum = 0x1504;
+pub const ALC_6POINT1_SOFT: ALCenum = 0x1505;
+pub const ALC_7POINT1_SOFT: ALCenum = 0x1506;
+
+pub const ALC_FORMAT_CHANNELS_SOFT: ALCenum = 0x1990;
+pub const ALC_FORMAT_TYPE_SOFT:     ALCenum = 0x1991;
+
+entry! { alc;
+	pub fn alcLoopbackOpenDeviceSOFT(name: *const ALCchar) -> *mut ALCdevice;
+	pub fn alcIsRenderFormatSupportedSOFT(device: *mut ALCdevice, frequency: ALCsizei, channels: ALCenum, kind: ALCenum) -> ALCboolean;
+	pub fn alcRenderSamplesSOFT(device: *mut ALCdevice, buffer: *mut ALCvoid, samples: ALCsizei);
+}
+
+// ALC_SOFT_HRTF
+pub const ALC_DONT_CARE_SOFT:           ALCenum = 0x0002;
+pub const ALC_HRTF_SOFT:                ALCenum = 0x1992;
+pub const ALC_HRTF_STATUS_SOFT:         ALCenum = 0x1993;
+pub const ALC_NUM_HRTF_SPECIFIERS_SOFT: ALCenum = 0x1994;
+pub const ALC_HRTF_SPECIFIER_SOFT:      ALCenum = 0x1995;
+pub const ALC_HRTF_ID_SOFT:             ALCenum = 0x1996;
+
+pub const ALC_HRTF_DISABLED_SOFT:            ALCenum = 0x0000;
+pub const ALC_HRTF_ENABLED_SOFT:             ALCenum = 0x0001;
+pub const ALC_HRTF_DENIED_SOFT:              ALCenum = 0x0002;
+pub const ALC_HRTF_REQUIRED_SOFT:            ALCenum = 0x0003;
+pub const ALC_HRTF_HEADPHONES_DETECTED_SOFT: ALCenum = 0x0004;
+pub const ALC_HRTF_UNSUPPORTED_FORMAT_SOFT:  ALCenum = 0x0005;
+
+entry! { alc;
+	pub fn alcGetStringiSOFT(device: *mut ALCdevice, param: ALCenum, index: ALCsizei) -> *const ALCchar;
+	pub fn alcResetDeviceSOFT(device: *mut ALCdevice, attributes: *const ALCint) -> ALCboolean;
+}
+
+// ALC_EXT_EFX
+pub const ALC_EFX_MAJOR_VERSION:   ALCenum = 0x20001;
+pub const ALC_EFX_MINOR_VERSION:   ALCenum = 0x20002;
+pub const ALC_MAX_AUXILIARY_SENDS: ALCenum = 0x20003;
+
+pub const AL_METERS_PER_UNIT: ALenum = 0x20004;
+
+pub const AL_DIRECT_FILTER:                     ALenum = 0x20005;
+pub const AL_AUXILIARY_SEND_FILTER:             ALenum = 0x20006;
+pub const AL_AIR_ABSORPTION_FACTOR:             ALenum = 0x20007;
+pub const AL_ROOM_ROLLOFF_FACTOR:               ALenum = 0x20008;
+pub const AL_CONE_OUTER_GAINHF:                 ALenum = 0x20009;
+pub const AL_DIRECT_FILTER_GAINHF_AUTO:         ALenum = 0x2000A;
+pub const AL_AUXILIARY_SEND_FILTER_GAIN_AUTO:   ALenum = 0x2000B;
+pub const AL_AUXILIARY_SEND_FILTER_GAINHF_AUTO: ALenum = 0x2000C;
+
+pub const AL_REVERB_DENSITY:               ALenum = 0x0001;
+pub const AL_REVERB_DIFFUSION:             ALenum = 0x0002;
+pub const AL_REVERB_GAIN:                  ALenum = 0x0003;
+pub const AL_REVERB_GAINHF:                ALenum = 0x0004;
+pub const AL_REVERB_DECAY_TIME:            ALenum = 0x0005;
+pub const AL_REVERB_DECAY_HFRATIO:         ALenum = 0x0006;
+pub const AL_REVERB_REFLECTIONS_GAIN:      ALenum = 0x0007;
+pub const AL_REVERB_REFLECTIONS_DELAY:     ALenum = 0x0008;
+pub const AL_REVERB_LATE_REVERB_GAIN:      ALenum = 0x0009;
+pub const AL_REVERB_LATE_REVERB_DELAY:     ALenum = 0x000A;
+pub const AL_REVERB_AIR_ABSORPTION_GAINHF: ALenum = 0x000B;
+pub const AL_REVERB_ROOM_ROLLOFF_FACTOR:   ALenum = 0x000C;
+pub const AL_REVERB_DECAY_HFLIMIT:         ALenum = 0x000D;
+
+pub const AL_EAXREVERB_DENSITY:               ALenum = 0x0001;
+pub const AL_EAXREVERB_DIFFUSION:             ALenum = 0x0002;
+pub const AL_EAXREVERB_GAIN:                  ALenum = 0x0003;
+pub const AL_EAXREVERB_GAINHF:                ALenum = 0x0004;
+pub const AL_EAXREVERB_GAINLF:                ALenum = 0x0005;
+pub const AL_EAXREVERB_DECAY_TIME:            ALenum = 0x0006;
+pub const AL_EAXREVERB_DECAY_HFRATIO:         ALenum = 0x0007;
+pub const AL_EAXREVERB_DECAY_LFRATIO:         ALenum = 0x0008;
+pub const AL_EAXREVERB_REFLECTIONS_GAIN:      ALenum = 0x0009;
+pub const AL_EAXREVERB_REFLECTIONS_DELAY:     ALenum = 0x000A;
+pub const AL_EAXREVERB_REFLECTIONS_PAN:       ALenum = 0x000B;
+pub const AL_EAXREVERB_LATE_REVERB_GAIN:      ALenum = 0x000C;
+pub const AL_EAXREVERB_LATE_REVERB_DELAY:     ALenum = 0x000D;
+pub const AL_EAXREVERB_LATE_REVERB_PAN:       ALenum = 0x000E;
+pub const AL_EAXREVERB_ECHO_TIME:             ALenum = 0x000F;
+pub const AL_EAXREVERB_ECHO_DEPTH:            ALenum = 0x0010;
+pub const AL_EAXREVERB_MODULATION_TIME:       ALenum = 0x0011;
+pub const AL_EAXREVERB_MODULATION_DEPTH:      ALenum = 0x0012;
+pub const AL_EAXREVERB_AIR_ABSORPTION_GAINHF: ALenum = 0x0013;
+pub const AL_EAXREVERB_HFREFERENCE:           ALenum = 0x0014;
+pub const AL_EAXREVERB_LFREFERENCE:           ALenum = 0x0015;
+pub const AL_EAXREVERB_ROOM_ROLLOFF_FACTOR:   ALenum = 0x0016;
+pub const AL_EAXREVERB_DECAY_HFLIMIT:         ALenum = 0x0017;
+
+pub const AL_CHORUS_WAVEFORM: ALenum = 0x0001;
+pub const AL_CHORUS_PHASE:    ALenum = 0x0002;
+pub const AL_CHORUS_RATE:     ALenum = 0x0003;
+pub const AL_CHORUS_DEPTH:    ALenum = 0x0004;
+pub const AL_CHORUS_FEEDBACK: ALenum = 0x0005;
+pub const AL_CHORUS_DELAY:    ALenum = 0x0006;
+
+pub const AL_DISTORTION_EDGE:           ALenum = 0x0001;
+pub const AL_DISTORTION_GAIN:           ALenum = 0x0002;
+pub const AL_DISTORTION_LOWPASS_CUTOFF: ALenum = 0x0003;
+pub const AL_DISTORTION_EQCENTER:       ALenum = 0x0004;
+pub const AL_DISTORTION_EQBANDWIDTH:    ALenum = 0x0005;
+
+pub const AL_ECHO_DELAY:    ALenum = 0x0001;
+pub const AL_ECHO_LRDELAY:  ALenum = 0x0002;
+pub const AL_ECHO_DAMPING:  ALenum = 0x0003;
+pub const AL_ECHO_FEEDBACK: ALenum = 0x0004;
+pub const AL_ECHO_SPREAD:   ALenum = 0x0005;
+
+pub const AL_FLANGER_WAVEFORM: ALenum = 0x0001;
+pub const AL_FLANGER_PHASE:    ALenum = 0x0002;
+pub const AL_FLANGER_RATE:     ALenum = 0x0003;
+pub const AL_FLANGER_DEPTH:    ALenum = 0x0004;
+pub const AL_FLANGER_FEEDBACK: ALenum = 0x0005;
+pub const AL_FLANGER_DELAY:    ALenum = 0x0006;
+
+pub const AL_FREQUENCY_SHIFTER_FREQUENCY:       ALenum = 0x0001;
+pub const AL_FREQUENCY_SHIFTER_LEFT_DIRECTION:  ALenum = 0x0002;
+pub const AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION: ALenum = 0x0003;
+
+pub const AL_VOCAL_MORPHER_PHONEMEA:               ALenum = 0x0001;
+pub const AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING: ALenum = 0x0002;
+pub const AL_VOCAL_MORPHER_PHONEMEB:               ALenum = 0x0003;
+pub const AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING: ALenum = 0x0004;
+pub const AL_VOCAL_MORPHER_WAVEFORM:               ALenum = 0x0005;
+pub const AL_VOCAL_MORPHER_RATE:                   ALenum = 0x0006;
+
+pub const AL_PITCH_SHIFTER_COARSE_TUNE: ALenum = 0x0001;
+pub const AL_PITCH_SHIFTER_FINE_TUNE:   ALenum = 0x0002;
+
+pub const AL_RING_MODULATOR_FREQUENCY:       ALenum = 0x0001;
+pub const AL_RING_MODULATOR_HIGHPASS_CUTOFF: ALenum = 0x0002;
+pub const AL_RING_MODULATOR_WAVEFORM:        ALenum = 0x0003;
+
+pub const AL_AUTOWAH_ATTACK_TIME:  ALenum = 0x0001;
+pub const AL_AUTOWAH_RELEASE_TIME: ALenum = 0x0002;
+pub const AL_AUTOWAH_RESONANCE:    ALenum = 0x0003;
+pub const AL_AUTOWAH_PEAK_GAIN:    ALenum = 0x0004;
+
+pub const AL_COMPRESSOR_ONOFF: ALenum = 0x0001;
+
+pub const AL_EQUALIZER_LOW_GAIN:    ALenum = 0x0001;
+pub const AL_EQUALIZER_LOW_CUTOFF:  ALenum = 0x0002;
+pub const AL_EQUALIZER_MID1_GAIN:   ALenum = 0x0003;
+pub const AL_EQUALIZER_MID1_CENTER: ALenum = 0x0004;
+pub const AL_EQUALIZER_MID1_WIDTH:  ALenum = 0x0005;
+pub const AL_EQUALIZER_MID2_GAIN:   ALenum = 0x0006;
+pub const AL_EQUALIZER_MID2_CENTER: ALenum = 0x0007;
+pub const AL_EQUALIZER_MID2_WIDTH:  ALenum = 0x0008;
+pub const AL_EQUALIZER_HIGH_GAIN:   ALenum = 0x0009;
+pub const AL_EQUALIZER_HIGH_CUTOFF: ALenum = 0x000A;
+
+pub const AL_EFFECT_TYPE: ALenum = 0x8001;
+
+pub const AL_EFFECT_NULL:              ALenum = 0x0000;
+pub const AL_EFFECT_REVERB:            ALenum = 0x0001;
+pub const AL_EFFECT_CHORUS:            ALenum = 0x0002;
+pub const AL_EFFECT_DISTORTION:        ALenum = 0x0003;
+pub const AL_EFFECT_ECHO:              ALenum = 0x0004;
+pub const AL_EFFECT_FLANGER:           ALenum = 0x0005;
+pub const AL_EFFECT_FREQUENCY_SHIFTER: ALenum = 0x0006;
+pub const AL_EFFECT_VOCAL_MORPHER:     ALenum = 0x0007;
+pub const AL_EFFECT_PITCH_SHIFTER:     ALenum = 0x0008;
+pub const AL_EFFECT_RING_MODULATOR:    ALenum = 0x0009;
+pub const AL_EFFECT_AUTOWAH:           ALenum = 0x000A;
+pub const AL_EFFECT_COMPRESSOR:        ALenum = 0x000B;
+pub const AL_EFFECT_EQUALIZER:         ALenum = 0x000C;
+pub const AL_EFFECT_EAXREVERB:         ALenum = 0x8000;
+
+pub const AL_EFFECTSLOT_EFFECT:             ALenum = 0x0001;
+pub const AL_EFFECTSLOT_GAIN:               ALenum = 0x0002;
+pub const AL_EFFECTSLOT_AUXILIARY_SEND_AUTO: ALenum = 0x0003;
+pub const AL_EFFECTSLOT_NULL:               ALenum = 0x0000;
+
+pub const AL_LOWPASS_GAIN:    ALenum = 0x0001;
+pub const AL_LOWPASS_GAINHF:  ALenum = 0x0002;
+pub const AL_HIGHPASS_GAIN:   ALenum = 0x0001;
+pub const AL_HIGHPASS_GAINLF: ALenum = 0x0002;
+pub const AL_BANDPASS_GAIN:   ALenum = 0x0001;
+pub const AL_BANDPASS_GAINLF: ALenum = 0x0002;
+pub const AL_BANDPASS_GAINHF: ALenum = 0x0003;
+
+pub const AL_FILTER_TYPE: ALenum = 0x8001;
+
+pub const AL_FILTER_NULL:     ALenum = 0x0000;
+pub const AL_FILTER_LOWPASS:  ALenum = 0x0001;
+pub const AL_FILTER_HIGHPASS: ALenum = 0x0002;
+pub const AL_FILTER_BANDPASS: ALenum = 0x0003;
+
+entry! { al;
+	pub fn alGenEffects(n: ALsizei, effects: *mut ALuint);
+	pub fn alDeleteEffects(n: ALsizei, effects: *const ALuint);
+	pub fn alIsEffect(effect: ALuint) -> ALboolean;
+	pub fn alEffecti(effect: ALuint, param: ALenum, value: ALint);
+	pub fn alEffectf(effect: ALuint, param: ALenum, value: ALfloat);
+	pub fn alEffectfv(effect: ALuint, param: ALenum, values: *const ALfloat);
+	pub fn alGetEffecti(effect: ALuint, param: ALenum, value: *mut ALint);
+	pub fn alGetEffectf(effect: ALuint, param: ALenum, value: *mut ALfloat);
+	pub fn alGetEffectfv(effect: ALuint, param: ALenum, values: *mut ALfloat);
+
+	pub fn alGenFilters(n: ALsizei, filters: *mut ALuint);
+	pub fn alDeleteFilters(n: ALsizei, filters: *const ALuint);
+	pub fn alIsFilter(filter: ALuint) -> ALboolean;
+	pub fn alFilteri(filter: ALuint, param: ALenum, value: ALint);
+	pub fn alFilterf(filter: ALuint, param: ALenum, value: ALfloat);
+	pub fn alGetFilteri(filter: ALuint, param: ALenum, value: *mut ALint);
+	pub fn alGetFilterf(filter: ALuint, param: ALenum, value: *mut ALfloat);
+
+	pub fn alGenAuxiliaryEffectSlots(n: ALsizei, slots: *mut ALuint);
+	pub fn alDeleteAuxiliaryEffectSlots(n: ALsizei, slots: *const ALuint);
+	pub fn alIsAuxiliaryEffectSlot(slot: ALuint) -> ALboolean;
+	pub fn alAuxiliaryEffectSloti(slot: ALuint, param: ALenum, value: ALint);
+	pub fn alAuxiliaryEffectSlotf(slot: ALuint, param: ALenum, value: ALfloat);
+	pub fn alGetAuxiliaryEffectSloti(slot: ALuint, param: ALenum, value: *mut ALint);
+	pub fn alGetAuxiliaryEffectSlotf(slot: ALuint, param: ALenum, value: *mut ALfloat);
+}
+
+// ALC_EXT_thread_local_context
+entry! { alc;
+	pub fn alcSetThreadContext(context: *mut ALCcontext) -> ALCboolean;
+	pub fn alcGetThreadContext() -> *mut ALCcontext;
+}
+
+// ALC_EXT_disconnect
+pub const ALC_CONNECTED: ALCenum = 0x0313;
+
+// ALC_SOFT_reopen_device
+entry! { alc;
+	pub fn alcReopenDeviceSOFT(device: *mut ALCdevice, name: *const ALCchar, attributes: *const ALCint) -> ALCboolean;
+}
+
+// ALC_SOFT_system_events
+#[allow(non_camel_case_types)]
+pub type ALCEVENTPROCTYPESOFT = Option<unsafe extern "C" fn(kind: ALCenum, device_type: ALCenum, device: *mut ALCdevice, length: ALCsizei, message: *const ALCchar, user: *mut ALCvoid)>;
+
+pub const ALC_PLAYBACK_DEVICE_SOFT:                   ALCenum = 0x19D4;
+pub const ALC_CAPTURE_DEVICE_SOFT:                    ALCenum = 0x19D5;
+pub const ALC_EVENT_TYPE_DEFAULT_DEVICE_CHANGED_SOFT: ALCenum = 0x19D6;
+pub const ALC_EVENT_TYPE_DEVICE_ADDED_SOFT:           ALCenum = 0x19D7;
+pub const ALC_EVENT_TYPE_DEVICE_REMOVED_SOFT:         ALCenum = 0x19D8;
+pub const ALC_EVENT_SUPPORTED_SOFT:                   ALCenum = 0x19D9;
+pub const ALC_EVENT_NOT_SUPPORTED_SOFT:               ALCenum = 0x19DA;
+
+entry! { alc;
+	pub fn alcEventIsSupportedSOFT(kind: ALCenum, device_type: ALCenum) -> ALCenum;
+	pub fn alcEventControlSOFT(count: ALCsizei, kinds: *const ALCenum, enable: ALCboolean) -> ALCboolean;
+	pub fn alcEventCallbackSOFT(callback: ALCEVENTPROCTYPESOFT, user: *mut ALCvoid);
+}
+
+// ALC_SOFT_pause_device
+entry! { alc;
+	pub fn alcDevicePauseSOFT(device: *mut ALCdevice);
+	pub fn alcDeviceResumeSOFT(device: *mut ALCdevice);
+}
@@ -1,5 +1,11 @@
+use std::time::Duration;
+use std::ffi::CStr;
+use std::sync::{Mutex, MutexGuard};
+
 use ffi::*;
-use Error;
+use sys::*;
+use {Error, extension};
+use listener::Current;
 
 /// A 3D vector.
 #[derive(PartialEq, Copy, Clone, Debug)]
@@ -45,10 +51,153 @@ pub struct Doppler {
 	pub velocity: f32,
 }
 
+/// The model used to attenuate `Source`s over distance.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum DistanceModel {
+	/// No attenuation.
+	None,
+
+	/// Inverse distance rolloff.
+	Inverse,
+
+	/// Inverse distance rolloff, clamped between the reference and maximum
+	/// distance.
+	InverseClamped,
+
+	/// Linear rolloff.
+	Linear,
+
+	/// Linear rolloff, clamped between the reference and maximum distance.
+	LinearClamped,
+
+	/// Exponential rolloff.
+	Exponent,
+
+	/// Exponential rolloff, clamped between the reference and maximum
+	/// distance.
+	ExponentClamped,
+}
+
+impl From<ALenum> for DistanceModel {
+	fn from(value: ALenum) -> DistanceModel {
+		match value {
+			AL_INVERSE_DISTANCE          => DistanceModel::Inverse,
+			AL_INVERSE_DISTANCE_CLAMPED  => DistanceModel::InverseClamped,
+			AL_LINEAR_DISTANCE           => DistanceModel::Linear,
+			AL_LINEAR_DISTANCE_CLAMPED   => DistanceModel::LinearClamped,
+			AL_EXPONENT_DISTANCE         => DistanceModel::Exponent,
+			AL_EXPONENT_DISTANCE_CLAMPED => DistanceModel::ExponentClamped,
+
+			_ => DistanceModel::None,
+		}
+	}
+}
+
+impl From<DistanceModel> for ALenum {
+	fn from(value: DistanceModel) -> ALenum {
+		match value {
+			DistanceModel::None            => AL_NONE,
+			DistanceModel::Inverse         => AL_INVERSE_DISTANCE,
+			DistanceModel::InverseClamped  => AL_INVERSE_DISTANCE_CLAMPED,
+			DistanceModel::Linear          => AL_LINEAR_DISTANCE,
+			DistanceModel::LinearClamped   => AL_LINEAR_DISTANCE_CLAMPED,
+			DistanceModel::Exponent        => AL_EXPONENT_DISTANCE,
+			DistanceModel::ExponentClamped => AL_EXPONENT_DISTANCE_CLAMPED,
+		}
+	}
+}
+
 /// A trait for defining a conversion between a type and an OpenAL format.
 pub trait Sample {
 	/// Returns the OpenAL format based on the number of channels.
 	fn format(channels: u16) -> Result<ALenum, Error>;
+
+	/// Returns the OpenAL sample type used when rendering to a loopback device,
+	/// by default rendering is not supported.
+	fn render_type() -> Result<ALCenum, Error> {
+		Err(Error::Unsupported("Loopback rendering of this sample type"))
+	}
+}
+
+/// Converts a count of nanoseconds as returned by OpenAL to a `Duration`,
+/// negative values are clamped to zero.
+pub fn duration(nanoseconds: i64) -> Duration {
+	if nanoseconds <= 0 {
+		return Duration::new(0, 0);
+	}
+
+	Duration::new((nanoseconds / 1_000_000_000) as u64, (nanoseconds % 1_000_000_000) as u32)
+}
+
+// The device lists returned by `alcGetString` live in storage the
+// implementation rebuilds on every enumeration.
+static ENUMERATION: Mutex<()> = Mutex::new(());
+
+/// Locks device enumeration, hold it while using the pointers returned by
+/// `alcGetString` for device specifiers.
+pub fn enumeration() -> MutexGuard<'static, ()> {
+	match ENUMERATION.lock() {
+		Ok(guard) =>
+			guard,
+
+		Err(error) =>
+			error.into_inner()
+	}
+}
+
+/// Copies the string returned by `alcGetString`.
+pub unsafe fn string(ptr: *const ALCchar) -> Option<String> {
+	if ptr.is_null() || *ptr == 0 {
+		None
+	}
+	else {
+		Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+	}
+}
+
+/// Copies the list of strings returned by `alcGetString`, separated by a nul
+/// and terminated by two.
+pub unsafe fn strings(mut ptr: *const ALCchar) -> Vec<String> {
+	let mut result = Vec::new();
+
+	if ptr.is_null() {
+		return result;
+	}
+
+	while *ptr != 0 {
+		let string = CStr::from_ptr(ptr);
+
+		result.push(string.to_string_lossy().into_owned());
+		ptr = ptr.offset(string.to_bytes().len() as isize + 1);
+	}
+
+	result
+}
+
+/// Checks the extension is supported, the check is skipped when there's no
+/// current context since extensions can't be queried without one.
+fn require(name: &'static str) -> Result<(), Error> {
+	if !Current::get().is_null() && !extension::is_supported(name) {
+		return Err(Error::Unsupported(name));
+	}
+
+	Ok(())
+}
+
+/// Returns the multichannel format for the number of channels from the given
+/// quad, 5.1, 6.1 and 7.1 formats.
+fn multichannel(channels: u16, formats: [ALenum; 4]) -> Result<ALenum, Error> {
+	let format = match channels {
+		4 => formats[0],
+		6 => formats[1],
+		7 => formats[2],
+		8 => formats[3],
+		_ => return Err(Error::Unsupported("Formats with this channel count")),
+	};
+
+	try!(require("AL_EXT_MCFORMATS"));
+
+	Ok(format)
 }
 
 impl Sample for u8 {
@@ -56,9 +205,13 @@ impl Sample for u8 {
 		match channels {
 			1 => Ok(AL_FORMAT_MONO8),
 			2 => Ok(AL_FORMAT_STEREO8),
-			_ => Err(Error::InvalidValue),
+			_ => multichannel(channels, [AL_FORMAT_QUAD8, AL_FORMAT_51CHN8, AL_FORMAT_61CHN8, AL_FORMAT_71CHN8]),
 		}
 	}
+
+	fn render_type() -> Result<ALCenum, Error> {
+		Ok(ALC_UNSIGNED_BYTE_SOFT)
+	}
 }
 
 impl Sample for i16 {
@@ -66,9 +219,49 @@ impl Sample for i16 {
 		match channels {
 			1 => Ok(AL_FORMAT_MONO16),
 			2 => Ok(AL_FORMAT_STEREO16),
-			_ => Err(Error::InvalidValue),
+			_ => multichannel(channels, [AL_FORMAT_QUAD16, AL_FORMAT_51CHN16, AL_FORMAT_61CHN16, AL_FORMAT_71CHN16]),
 		}
 	}
+
+	fn render_type() -> Result<ALCenum, Error> {
+		Ok(ALC_SHORT_SOFT)
+	}
+}
+
+impl Sample for f32 {
+	fn format(channels: u16) -> Result<ALenum, Error> {
+		let format = try!(match channels {
+			1 => Ok(AL_FORMAT_MONO_FLOAT32),
+			2 => Ok(AL_FORMAT_STEREO_FLOAT32),
+			_ => multichannel(channels, [AL_FORMAT_QUAD32, AL_FORMAT_51CHN32, AL_FORMAT_61CHN32, AL_FORMAT_71CHN32]),
+		});
+
+		try!(require("AL_EXT_FLOAT32"));
+
+		Ok(format)
+	}
+
+	fn render_type() -> Result<ALCenum, Error> {
+		Ok(ALC_FLOAT_SOFT)
+	}
+}
+
+impl Sample for f64 {
+	fn format(channels: u16) -> Result<ALenum, Error> {
+		let format = try!(match channels {
+			1 => Ok(AL_FORMAT_MONO_DOUBLE_EXT),
+			2 => Ok(AL_FORMAT_STEREO_DOUBLE_EXT),
+			_ => Err(Error::Unsupported("Multichannel f64 formats")),
+		});
+
+		try!(require("AL_EXT_DOUBLE"));
+
+		Ok(format)
+	}
+
+	fn render_type() -> Result<ALCenum, Error> {
+		Err(Error::Unsupported("Loopback rendering of f64 samples"))
+	}
 }
 
 #[doc(hidden)]
@@ -0,0 +1,67 @@
+use std::io;
+use std::error;
+use std::fmt;
+
+/// WAV error type.
+#[derive(Debug)]
+pub enum Error {
+	/// An I/O error.
+	Io(io::Error),
+
+	/// The data is not a valid RIFF/WAVE file.
+	Malformed(&'static str),
+
+	/// The file is valid but uses a feature that is not supported.
+	Unsupported(&'static str),
+
+	/// An OpenAL error.
+	OpenAL(::Error),
+}
+
+impl From<io::Error> for Error {
+	fn from(value: io::Error) -> Error {
+		Error::Io(value)
+	}
+}
+
+impl From<::Error> for Error {
+	fn from(value: ::Error) -> Error {
+		Error::OpenAL(value)
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+		match self {
+			&Error::Io(ref error) =>
+				fmt::Display::fmt(error, f),
+
+			&Error::Malformed(reason) =>
+				f.write_str(&format!("Malformed WAV file: {}.", reason)),
+
+			&Error::Unsupported(reason) =>
+				f.write_str(&format!("Unsupported WAV file: {}.", reason)),
+
+			&Error::OpenAL(ref error) =>
+				fmt::Display::fmt(error, f),
+		}
+	}
+}
+
+impl error::Error for Error {
+	fn description(&self) -> &str {
+		match self {
+			&Error::Io(..) =>
+				"An I/O error.",
+
+			&Error::Malformed(..) =>
+				"The data is not a valid RIFF/WAVE file.",
+
+			&Error::Unsupported(..) =>
+				"The file uses a feature that is not supported.",
+
+			&Error::OpenAL(..) =>
+				"An OpenAL error.",
+		}
+	}
+}
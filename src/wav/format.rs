@@ -0,0 +1,132 @@
+use Sample;
+
+/// The encoding of the samples.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Encoding {
+	/// Integer PCM, unsigned for 8 bits and signed otherwise.
+	Integer,
+
+	/// IEEE floating point.
+	Float,
+}
+
+/// The format of a WAV file.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Format {
+	/// The encoding of the samples.
+	pub encoding: Encoding,
+
+	/// The number of channels.
+	pub channels: u16,
+
+	/// The sample rate.
+	pub rate: u32,
+
+	/// The bits per sample.
+	pub bits: u16,
+}
+
+impl Format {
+	/// The size of a frame in bytes.
+	pub fn frame(&self) -> usize {
+		self.channels as usize * (self.bits as usize / 8)
+	}
+}
+
+/// A `Sample` type WAV data can be decoded into.
+pub trait Decode: Sample + Copy {
+	/// Converts a sample normalized to `[-1.0, 1.0]`.
+	fn decode(value: f64) -> Self;
+}
+
+impl Decode for u8 {
+	fn decode(value: f64) -> Self {
+		(value * 128.0 + 128.0).round().max(0.0).min(255.0) as u8
+	}
+}
+
+impl Decode for i16 {
+	fn decode(value: f64) -> Self {
+		(value * 32768.0).round().max(-32768.0).min(32767.0) as i16
+	}
+}
+
+impl Decode for f32 {
+	fn decode(value: f64) -> Self {
+		value as f32
+	}
+}
+
+impl Decode for f64 {
+	fn decode(value: f64) -> Self {
+		value
+	}
+}
+
+/// A `Sample` type that can be encoded as WAV data.
+pub trait Encode: Sample + Copy {
+	/// The encoding the samples are written with.
+	fn encoding() -> Encoding;
+
+	/// The bits per sample.
+	fn bits() -> u16;
+
+	/// Appends the little-endian representation of the sample.
+	fn encode(&self, out: &mut Vec<u8>);
+}
+
+impl Encode for u8 {
+	fn encoding() -> Encoding {
+		Encoding::Integer
+	}
+
+	fn bits() -> u16 {
+		8
+	}
+
+	fn encode(&self, out: &mut Vec<u8>) {
+		out.push(*self);
+	}
+}
+
+impl Encode for i16 {
+	fn encoding() -> Encoding {
+		Encoding::Integer
+	}
+
+	fn bits() -> u16 {
+		16
+	}
+
+	fn encode(&self, out: &mut Vec<u8>) {
+		out.extend_from_slice(&self.to_le_bytes());
+	}
+}
+
+impl Encode for f32 {
+	fn encoding() -> Encoding {
+		Encoding::Float
+	}
+
+	fn bits() -> u16 {
+		32
+	}
+
+	fn encode(&self, out: &mut Vec<u8>) {
+		out.extend_from_slice(&self.to_bits().to_le_bytes());
+	}
+}
+
+impl Encode for f64 {
+	fn encoding() -> Encoding {
+		Encoding::Float
+	}
+
+	fn bits() -> u16 {
+		64
+	}
+
+	fn encode(&self, out: &mut Vec<u8>) {
+		out.extend_from_slice(&self.to_bits().to_le_bytes());
+	}
+}
@@ -0,0 +1,19 @@
+/*!
+RIFF/WAVE support.
+
+The `Reader` decodes PCM and IEEE float data, including
+`WAVE_FORMAT_EXTENSIBLE` files, into any `Sample` type, the `Writer` encodes
+`Sample`s from a `Capture` or a `Loopback` device.
+*/
+
+mod error;
+pub use self::error::Error;
+
+mod format;
+pub use self::format::{Format, Encoding, Decode, Encode};
+
+mod reader;
+pub use self::reader::Reader;
+
+mod writer;
+pub use self::writer::Writer;
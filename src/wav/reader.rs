@@ -0,0 +1,447 @@
+use std::io::{self, Read};
+
+use super::{Error, Format, Encoding, Decode};
+
+pub const WAVE_FORMAT_PCM:        u16 = 0x0001;
+pub const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+pub const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Reads samples from a RIFF/WAVE stream.
+pub struct Reader<R: Read> {
+	inner:     R,
+	format:    Format,
+	remaining: u64,
+	scratch:   Vec<u8>,
+}
+
+impl<R: Read> Reader<R> {
+	/// Parses the headers up to the start of the sample data.
+	pub fn new(mut inner: R) -> Result<Self, Error> {
+		let mut header = [0; 12];
+		try!(exact(&mut inner, &mut header));
+
+		if &header[0 .. 4] != b"RIFF" || &header[8 .. 12] != b"WAVE" {
+			return Err(Error::Malformed("missing RIFF/WAVE header"));
+		}
+
+		let mut format = None;
+
+		loop {
+			let mut chunk = [0; 8];
+			try!(exact(&mut inner, &mut chunk));
+
+			let size = u32(&chunk[4 .. 8]) as u64;
+
+			match &chunk[0 .. 4] {
+				b"fmt " => {
+					if size < 16 {
+						return Err(Error::Malformed("fmt chunk too small"));
+					}
+
+					// Read through `take` so a bogus size can't make us allocate
+					// more than the file actually contains.
+					let mut data = Vec::new();
+					try!((&mut inner).take(size).read_to_end(&mut data));
+
+					if (data.len() as u64) < size {
+						return Err(Error::Malformed("unexpected end of file"));
+					}
+
+					try!(skip(&mut inner, size & 1));
+
+					format = Some(try!(parse(&data)));
+				}
+
+				b"data" => {
+					let format = match format {
+						Some(format) =>
+							format,
+
+						None =>
+							return Err(Error::Malformed("data chunk before fmt chunk"))
+					};
+
+					return Ok(Reader {
+						inner:     inner,
+						format:    format,
+						remaining: size,
+						scratch:   Vec::new(),
+					});
+				}
+
+				_ =>
+					try!(skip(&mut inner, size + (size & 1)))
+			}
+		}
+	}
+
+	/// The format of the samples.
+	pub fn format(&self) -> Format {
+		self.format
+	}
+
+	/// The number of frames left to read, as declared by the file.
+	pub fn len(&self) -> usize {
+		(self.remaining / self.format.frame() as u64) as usize
+	}
+
+	/// Reads whole frames into the slice converting them, returning the number
+	/// of samples read, 0 marks the end of the data.
+	///
+	/// The slice must be able to hold at least one frame.
+	pub fn read<T: Decode>(&mut self, out: &mut [T]) -> Result<usize, Error> {
+		let channels = self.format.channels as usize;
+
+		if out.len() < channels {
+			return Err(Error::Unsupported("reading into a slice smaller than a frame"));
+		}
+
+		let width    = self.format.bits as usize / 8;
+		let samples  = (out.len() / channels) * channels;
+		let bytes    = ((samples * width) as u64).min(self.remaining);
+
+		self.scratch.clear();
+		try!((&mut self.inner).take(bytes).read_to_end(&mut self.scratch));
+
+		let frames = self.scratch.len() / self.format.frame();
+		self.remaining -= self.scratch.len() as u64;
+
+		if frames * self.format.frame() != self.scratch.len() {
+			self.remaining = 0;
+		}
+
+		for (sample, bytes) in out.iter_mut().zip(self.scratch.chunks(width).take(frames * channels)) {
+			*sample = T::decode(decode(self.format.encoding, bytes));
+		}
+
+		Ok(frames * channels)
+	}
+
+	/// Reads all the remaining samples.
+	pub fn samples<T: Decode>(&mut self) -> Result<Vec<T>, Error> {
+		// The declared length can't be trusted, only reserve up to a second or
+		// so of data upfront and grow with what's actually read.
+		let mut result = Vec::with_capacity(self.len().min(65536) * self.format.channels as usize);
+		let mut chunk  = vec![T::decode(0.0); 4096 * self.format.channels as usize];
+
+		loop {
+			match try!(self.read(&mut chunk)) {
+				0 =>
+					return Ok(result),
+
+				length =>
+					result.extend_from_slice(&chunk[.. length])
+			}
+		}
+	}
+
+	/// Returns the underlying reader.
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+}
+
+impl<R: Read> ::std::fmt::Debug for Reader<R> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::wav::Reader("));
+		try!(f.write_str(&format!("{:?}; ", self.format)));
+		try!(f.write_str(&format!("len={}", self.len())));
+		f.write_str(")")
+	}
+}
+
+fn parse(data: &[u8]) -> Result<Format, Error> {
+	let mut tag  = u16(&data[0 .. 2]);
+	let channels = u16(&data[2 .. 4]);
+	let rate     = u32(&data[4 .. 8]);
+	let align    = u16(&data[12 .. 14]);
+	let bits     = u16(&data[14 .. 16]);
+
+	if tag == WAVE_FORMAT_EXTENSIBLE {
+		if data.len() < 40 {
+			return Err(Error::Malformed("fmt chunk too small for WAVE_FORMAT_EXTENSIBLE"));
+		}
+
+		tag = u16(&data[24 .. 26]);
+	}
+
+	let encoding = match (tag, bits) {
+		(WAVE_FORMAT_PCM, 8) | (WAVE_FORMAT_PCM, 16) | (WAVE_FORMAT_PCM, 24) | (WAVE_FORMAT_PCM, 32) =>
+			Encoding::Integer,
+
+		(WAVE_FORMAT_IEEE_FLOAT, 32) | (WAVE_FORMAT_IEEE_FLOAT, 64) =>
+			Encoding::Float,
+
+		(WAVE_FORMAT_PCM, _) | (WAVE_FORMAT_IEEE_FLOAT, _) =>
+			return Err(Error::Unsupported("bits per sample")),
+
+		_ =>
+			return Err(Error::Unsupported("encoding"))
+	};
+
+	if channels == 0 || channels > 8 {
+		return Err(Error::Unsupported("channel count"));
+	}
+
+	if rate == 0 {
+		return Err(Error::Malformed("sample rate is zero"));
+	}
+
+	if align as usize != channels as usize * (bits as usize / 8) {
+		return Err(Error::Malformed("block align does not match the format"));
+	}
+
+	Ok(Format {
+		encoding: encoding,
+		channels: channels,
+		rate:     rate,
+		bits:     bits,
+	})
+}
+
+fn decode(encoding: Encoding, bytes: &[u8]) -> f64 {
+	match (encoding, bytes.len()) {
+		(Encoding::Integer, 1) =>
+			(bytes[0] as f64 - 128.0) / 128.0,
+
+		(Encoding::Integer, 2) =>
+			(u16(bytes) as i16) as f64 / 32768.0,
+
+		(Encoding::Integer, 3) =>
+			((((bytes[2] as u32) << 24 | (bytes[1] as u32) << 16 | (bytes[0] as u32) << 8) as i32) >> 8) as f64 / 8388608.0,
+
+		(Encoding::Integer, _) =>
+			(u32(bytes) as i32) as f64 / 2147483648.0,
+
+		(Encoding::Float, 4) =>
+			f32::from_bits(u32(bytes)) as f64,
+
+		(Encoding::Float, _) =>
+			f64::from_bits(u32(&bytes[0 .. 4]) as u64 | (u32(&bytes[4 .. 8]) as u64) << 32),
+	}
+}
+
+fn u16(bytes: &[u8]) -> u16 {
+	bytes[0] as u16 | (bytes[1] as u16) << 8
+}
+
+fn u32(bytes: &[u8]) -> u32 {
+	bytes[0] as u32 | (bytes[1] as u32) << 8 | (bytes[2] as u32) << 16 | (bytes[3] as u32) << 24
+}
+
+fn exact<R: Read>(reader: &mut R, buffer: &mut [u8]) -> Result<(), Error> {
+	match reader.read_exact(buffer) {
+		Ok(..) =>
+			Ok(()),
+
+		Err(ref error) if error.kind() == io::ErrorKind::UnexpectedEof =>
+			Err(Error::Malformed("unexpected end of file")),
+
+		Err(error) =>
+			Err(Error::Io(error))
+	}
+}
+
+fn skip<R: Read>(reader: &mut R, bytes: u64) -> Result<(), Error> {
+	if try!(io::copy(&mut reader.take(bytes), &mut io::sink())) != bytes {
+		return Err(Error::Malformed("unexpected end of file"));
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::Cursor;
+
+	use wav::{Error, Encoding};
+	use super::Reader;
+
+	fn fmt(tag: u16, channels: u16, rate: u32, bits: u16) -> Vec<u8> {
+		let align = channels * bits / 8;
+		let mut result = Vec::new();
+
+		result.extend_from_slice(&tag.to_le_bytes());
+		result.extend_from_slice(&channels.to_le_bytes());
+		result.extend_from_slice(&rate.to_le_bytes());
+		result.extend_from_slice(&(rate * align as u32).to_le_bytes());
+		result.extend_from_slice(&align.to_le_bytes());
+		result.extend_from_slice(&bits.to_le_bytes());
+
+		result
+	}
+
+	fn extensible(tag: u16, channels: u16, rate: u32, bits: u16, mask: u32) -> Vec<u8> {
+		let mut result = fmt(0xFFFE, channels, rate, bits);
+
+		result.extend_from_slice(&22u16.to_le_bytes());
+		result.extend_from_slice(&bits.to_le_bytes());
+		result.extend_from_slice(&mask.to_le_bytes());
+		result.extend_from_slice(&tag.to_le_bytes());
+		result.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71]);
+
+		result
+	}
+
+	fn chunk(id: &[u8], data: &[u8]) -> Vec<u8> {
+		let mut result = Vec::new();
+
+		result.extend_from_slice(id);
+		result.extend_from_slice(&(data.len() as u32).to_le_bytes());
+		result.extend_from_slice(data);
+
+		if data.len() & 1 == 1 {
+			result.push(0);
+		}
+
+		result
+	}
+
+	fn riff(chunks: &[Vec<u8>]) -> Vec<u8> {
+		let body = chunks.concat();
+		let mut result = Vec::new();
+
+		result.extend_from_slice(b"RIFF");
+		result.extend_from_slice(&(body.len() as u32 + 4).to_le_bytes());
+		result.extend_from_slice(b"WAVE");
+		result.extend_from_slice(&body);
+
+		result
+	}
+
+	fn open(data: Vec<u8>) -> Reader<Cursor<Vec<u8>>> {
+		Reader::new(Cursor::new(data)).unwrap()
+	}
+
+	#[test]
+	fn pcm8() {
+		let mut reader = open(riff(&[chunk(b"fmt ", &fmt(1, 1, 8000, 8)), chunk(b"data", &[0, 128, 255])]));
+
+		assert_eq!(reader.format().encoding, Encoding::Integer);
+		assert_eq!(reader.format().bits, 8);
+		assert_eq!(reader.len(), 3);
+		assert_eq!(reader.samples::<u8>().unwrap(), vec![0, 128, 255]);
+	}
+
+	#[test]
+	fn pcm16() {
+		let data = [-32768i16, 0, 32767, -1].iter().flat_map(|v| v.to_le_bytes().to_vec()).collect::<Vec<u8>>();
+		let mut reader = open(riff(&[chunk(b"fmt ", &fmt(1, 2, 44100, 16)), chunk(b"data", &data)]));
+
+		assert_eq!(reader.format().channels, 2);
+		assert_eq!(reader.len(), 2);
+		assert_eq!(reader.samples::<i16>().unwrap(), vec![-32768, 0, 32767, -1]);
+	}
+
+	#[test]
+	fn pcm24() {
+		let mut reader = open(riff(&[chunk(b"fmt ", &fmt(1, 1, 48000, 24)), chunk(b"data", &[0x00, 0x00, 0x80, 0x00, 0x00, 0x40, 0xFF, 0xFF, 0xFF])]));
+
+		assert_eq!(reader.samples::<f64>().unwrap(), vec![-1.0, 0.5, -1.0 / 8388608.0]);
+	}
+
+	#[test]
+	fn pcm32() {
+		let data = [i32::min_value(), 1 << 30].iter().flat_map(|v| v.to_le_bytes().to_vec()).collect::<Vec<u8>>();
+		let mut reader = open(riff(&[chunk(b"fmt ", &fmt(1, 1, 48000, 32)), chunk(b"data", &data)]));
+
+		assert_eq!(reader.samples::<f32>().unwrap(), vec![-1.0, 0.5]);
+	}
+
+	#[test]
+	fn float32() {
+		let data = [0.5f32, -0.25].iter().flat_map(|v| v.to_bits().to_le_bytes().to_vec()).collect::<Vec<u8>>();
+		let mut reader = open(riff(&[chunk(b"fmt ", &fmt(3, 1, 48000, 32)), chunk(b"data", &data)]));
+
+		assert_eq!(reader.format().encoding, Encoding::Float);
+		assert_eq!(reader.samples::<f32>().unwrap(), vec![0.5, -0.25]);
+	}
+
+	#[test]
+	fn float64() {
+		let data = [0.125f64, -1.0].iter().flat_map(|v| v.to_bits().to_le_bytes().to_vec()).collect::<Vec<u8>>();
+		let mut reader = open(riff(&[chunk(b"fmt ", &fmt(3, 1, 48000, 64)), chunk(b"data", &data)]));
+
+		assert_eq!(reader.samples::<f64>().unwrap(), vec![0.125, -1.0]);
+		assert_eq!(open(riff(&[chunk(b"fmt ", &fmt(3, 1, 48000, 64)), chunk(b"data", &data)])).samples::<i16>().unwrap(), vec![4096, -32768]);
+	}
+
+	#[test]
+	fn wave_format_extensible() {
+		let data = (0 .. 12i16).flat_map(|v| v.to_le_bytes().to_vec()).collect::<Vec<u8>>();
+		let mut reader = open(riff(&[chunk(b"fmt ", &extensible(1, 6, 48000, 16, 0x3F)), chunk(b"data", &data)]));
+
+		assert_eq!(reader.format().encoding, Encoding::Integer);
+		assert_eq!(reader.format().channels, 6);
+		assert_eq!(reader.len(), 2);
+		assert_eq!(reader.samples::<i16>().unwrap(), (0 .. 12).collect::<Vec<i16>>());
+	}
+
+	#[test]
+	fn odd_chunk_padding() {
+		let mut reader = open(riff(&[chunk(b"LIST", &[1, 2, 3]), chunk(b"fmt ", &fmt(1, 1, 8000, 8)), chunk(b"junk", &[4]), chunk(b"data", &[10, 20, 30])]));
+
+		assert_eq!(reader.samples::<u8>().unwrap(), vec![10, 20, 30]);
+	}
+
+	#[test]
+	fn truncated_data() {
+		let mut file = riff(&[chunk(b"fmt ", &fmt(1, 2, 44100, 16))]);
+		file.extend_from_slice(b"data");
+		file.extend_from_slice(&8u32.to_le_bytes());
+		file.extend_from_slice(&[1, 0, 2, 0, 3]);
+
+		let mut reader = open(file);
+
+		assert_eq!(reader.len(), 2);
+		assert_eq!(reader.samples::<i16>().unwrap(), vec![1, 2]);
+	}
+
+	#[test]
+	fn oversized_data() {
+		let mut file = riff(&[chunk(b"fmt ", &fmt(1, 1, 8000, 8))]);
+		file.extend_from_slice(b"data");
+		file.extend_from_slice(&u32::max_value().to_le_bytes());
+		file.extend_from_slice(&[1, 2, 3]);
+
+		let mut reader = open(file);
+
+		assert_eq!(reader.len(), u32::max_value() as usize);
+		assert_eq!(reader.samples::<f64>().unwrap().len(), 3);
+	}
+
+	#[test]
+	fn truncated_header() {
+		let mut file = riff(&[]);
+		file.extend_from_slice(b"fmt ");
+		file.extend_from_slice(&u32::max_value().to_le_bytes());
+		file.extend_from_slice(&[0; 8]);
+
+		match Reader::new(Cursor::new(file)) {
+			Err(Error::Malformed(..)) => (),
+			result                    => panic!("unexpected {:?}", result),
+		}
+	}
+
+	#[test]
+	fn unsupported() {
+		match Reader::new(Cursor::new(riff(&[chunk(b"fmt ", &fmt(2, 1, 8000, 4)), chunk(b"data", &[])]))) {
+			Err(Error::Unsupported(..)) => (),
+			result                      => panic!("unexpected {:?}", result),
+		}
+	}
+
+	#[test]
+	fn slice_smaller_than_frame() {
+		let mut reader = open(riff(&[chunk(b"fmt ", &fmt(1, 2, 8000, 8)), chunk(b"data", &[1, 2, 3, 4])]));
+		let mut out    = [0u8; 3];
+
+		match reader.read(&mut out[.. 1]) {
+			Err(Error::Unsupported(..)) => (),
+			result                      => panic!("unexpected {:?}", result),
+		}
+
+		assert_eq!(reader.read(&mut out).unwrap(), 2);
+		assert_eq!(&out[.. 2], &[1, 2]);
+	}
+}
@@ -0,0 +1,262 @@
+use std::io::{Write, Seek, SeekFrom};
+use std::marker::PhantomData;
+
+use super::{Error, Format, Encoding, Encode};
+use super::reader::{WAVE_FORMAT_PCM, WAVE_FORMAT_IEEE_FLOAT, WAVE_FORMAT_EXTENSIBLE};
+
+/// Writes samples to a RIFF/WAVE stream.
+///
+/// The chunk sizes are patched when the `Writer` is finished or dropped.
+pub struct Writer<W: Write + Seek, T: Encode> {
+	inner:   Option<W>,
+	format:  Format,
+	start:   u64,
+	header:  u64,
+	written: u64,
+	scratch: Vec<u8>,
+
+	_marker: PhantomData<T>,
+}
+
+impl<W: Write + Seek, T: Encode> Writer<W, T> {
+	/// Writes the headers for the given channel count and sample rate.
+	pub fn new(mut inner: W, channels: u16, rate: u32) -> Result<Self, Error> {
+		if channels == 0 || channels > 8 {
+			return Err(Error::Unsupported("channel count"));
+		}
+
+		let format = Format {
+			encoding: T::encoding(),
+			channels: channels,
+			rate:     rate,
+			bits:     T::bits(),
+		};
+
+		let start  = try!(inner.seek(SeekFrom::Current(0)));
+		let header = header(&format);
+		try!(inner.write_all(&header));
+
+		Ok(Writer {
+			inner:   Some(inner),
+			format:  format,
+			start:   start,
+			header:  header.len() as u64,
+			written: 0,
+			scratch: Vec::new(),
+
+			_marker: PhantomData,
+		})
+	}
+
+	/// The format of the samples.
+	pub fn format(&self) -> Format {
+		self.format
+	}
+
+	/// The number of frames written.
+	pub fn len(&self) -> usize {
+		(self.written / self.format.frame() as u64) as usize
+	}
+
+	/// Writes interleaved samples, the length of the slice must be a multiple
+	/// of the number of channels.
+	pub fn write(&mut self, data: &[T]) -> Result<(), Error> {
+		if data.len() % self.format.channels as usize != 0 {
+			return Err(Error::Unsupported("writing partial frames"));
+		}
+
+		self.scratch.clear();
+
+		for sample in data {
+			sample.encode(&mut self.scratch);
+		}
+
+		if self.header + self.written + self.scratch.len() as u64 > u32::max_value() as u64 {
+			return Err(Error::Unsupported("files larger than 4GiB"));
+		}
+
+		try!(self.inner.as_mut().unwrap().write_all(&self.scratch));
+		self.written += self.scratch.len() as u64;
+
+		Ok(())
+	}
+
+	/// Patches the chunk sizes and returns the underlying writer.
+	pub fn finish(mut self) -> Result<W, Error> {
+		try!(self.patch());
+
+		Ok(self.inner.take().unwrap())
+	}
+
+	fn patch(&mut self) -> Result<(), Error> {
+		let inner = self.inner.as_mut().unwrap();
+
+		if self.written & 1 == 1 {
+			try!(inner.write_all(&[0]));
+		}
+
+		let end  = try!(inner.seek(SeekFrom::Current(0)));
+		let riff = self.header - 8 + self.written + (self.written & 1);
+
+		try!(inner.seek(SeekFrom::Start(self.start + 4)));
+		try!(inner.write_all(&(riff as u32).to_le_bytes()));
+		try!(inner.seek(SeekFrom::Start(self.start + self.header - 4)));
+		try!(inner.write_all(&(self.written as u32).to_le_bytes()));
+		try!(inner.seek(SeekFrom::Start(end)));
+		try!(inner.flush());
+
+		Ok(())
+	}
+}
+
+impl<W: Write + Seek, T: Encode> ::std::fmt::Debug for Writer<W, T> {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+		try!(f.write_str("openal::wav::Writer("));
+		try!(f.write_str(&format!("{:?}; ", self.format)));
+		try!(f.write_str(&format!("len={}", self.len())));
+		f.write_str(")")
+	}
+}
+
+impl<W: Write + Seek, T: Encode> Drop for Writer<W, T> {
+	fn drop(&mut self) {
+		if self.inner.is_some() {
+			self.patch().ok();
+		}
+	}
+}
+
+fn header(format: &Format) -> Vec<u8> {
+	let extensible = format.channels > 2;
+	let tag        = match format.encoding {
+		Encoding::Integer => WAVE_FORMAT_PCM,
+		Encoding::Float   => WAVE_FORMAT_IEEE_FLOAT,
+	};
+
+	let align = format.frame() as u16;
+	let mut result = Vec::with_capacity(68);
+
+	result.extend_from_slice(b"RIFF");
+	result.extend_from_slice(&[0; 4]);
+	result.extend_from_slice(b"WAVE");
+
+	result.extend_from_slice(b"fmt ");
+	result.extend_from_slice(&(if extensible { 40u32 } else { 16u32 }).to_le_bytes());
+	result.extend_from_slice(&(if extensible { WAVE_FORMAT_EXTENSIBLE } else { tag }).to_le_bytes());
+	result.extend_from_slice(&format.channels.to_le_bytes());
+	result.extend_from_slice(&format.rate.to_le_bytes());
+	result.extend_from_slice(&(format.rate * align as u32).to_le_bytes());
+	result.extend_from_slice(&align.to_le_bytes());
+	result.extend_from_slice(&format.bits.to_le_bytes());
+
+	if extensible {
+		let mask: u32 = match format.channels {
+			4 => 0x033,
+			6 => 0x03F,
+			7 => 0x70F,
+			8 => 0x63F,
+			_ => 0x000,
+		};
+
+		result.extend_from_slice(&22u16.to_le_bytes());
+		result.extend_from_slice(&format.bits.to_le_bytes());
+		result.extend_from_slice(&mask.to_le_bytes());
+		result.extend_from_slice(&tag.to_le_bytes());
+		result.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71]);
+	}
+
+	result.extend_from_slice(b"data");
+	result.extend_from_slice(&[0; 4]);
+
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::Cursor;
+
+	use wav::{Reader, Error, Encoding};
+	use super::Writer;
+
+	fn write<T: ::wav::Encode>(channels: u16, rate: u32, data: &[T]) -> Vec<u8> {
+		let mut writer = Writer::<_, T>::new(Cursor::new(Vec::new()), channels, rate).unwrap();
+		writer.write(data).unwrap();
+		writer.finish().unwrap().into_inner()
+	}
+
+	#[test]
+	fn round_trip_pcm8() {
+		let file = write(1, 8000, &[0u8, 128, 255]);
+
+		assert_eq!(file.len(), 44 + 4);
+		assert_eq!(&file[4 .. 8], &(40u32).to_le_bytes());
+		assert_eq!(&file[40 .. 44], &(3u32).to_le_bytes());
+
+		let mut reader = Reader::new(Cursor::new(file)).unwrap();
+		assert_eq!(reader.format().bits, 8);
+		assert_eq!(reader.samples::<u8>().unwrap(), vec![0, 128, 255]);
+	}
+
+	#[test]
+	fn round_trip_pcm16() {
+		let data = [-32768i16, 0, 32767, -1];
+		let mut reader = Reader::new(Cursor::new(write(2, 44100, &data))).unwrap();
+
+		assert_eq!(reader.format().channels, 2);
+		assert_eq!(reader.format().rate, 44100);
+		assert_eq!(reader.len(), 2);
+		assert_eq!(reader.samples::<i16>().unwrap(), data.to_vec());
+	}
+
+	#[test]
+	fn round_trip_float() {
+		let data = [0.5f32, -0.25];
+		let mut reader = Reader::new(Cursor::new(write(1, 48000, &data))).unwrap();
+
+		assert_eq!(reader.format().encoding, Encoding::Float);
+		assert_eq!(reader.samples::<f32>().unwrap(), data.to_vec());
+
+		let data = [0.125f64, -1.0];
+		let mut reader = Reader::new(Cursor::new(write(2, 48000, &data))).unwrap();
+
+		assert_eq!(reader.format().bits, 64);
+		assert_eq!(reader.samples::<f64>().unwrap(), data.to_vec());
+	}
+
+	#[test]
+	fn round_trip_extensible() {
+		let data = (0 .. 16i16).collect::<Vec<_>>();
+		let file = write(8, 48000, &data);
+
+		assert_eq!(&file[20 .. 22], &0xFFFEu16.to_le_bytes());
+
+		let mut reader = Reader::new(Cursor::new(file)).unwrap();
+		assert_eq!(reader.format().channels, 8);
+		assert_eq!(reader.samples::<i16>().unwrap(), data);
+	}
+
+	#[test]
+	fn partial_frame() {
+		let mut writer = Writer::<_, i16>::new(Cursor::new(Vec::new()), 2, 44100).unwrap();
+
+		match writer.write(&[1, 2, 3, 4, 5]) {
+			Err(Error::Unsupported(..)) => (),
+			result                      => panic!("unexpected {:?}", result),
+		}
+
+		assert_eq!(writer.len(), 0);
+	}
+
+	#[test]
+	fn patched_on_drop() {
+		let mut buffer = Cursor::new(Vec::new());
+
+		{
+			let mut writer = Writer::<_, i16>::new(&mut buffer, 1, 8000).unwrap();
+			writer.write(&[1, 2, 3]).unwrap();
+		}
+
+		let mut reader = Reader::new(Cursor::new(buffer.into_inner())).unwrap();
+		assert_eq!(reader.samples::<i16>().unwrap(), vec![1, 2, 3]);
+	}
+}